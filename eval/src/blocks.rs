@@ -0,0 +1,364 @@
+//! Preparation and inspection of the Reth block inputs in `eval/blocks`.
+
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use alloy_rlp::Decodable;
+use clap::{Args, Subcommand};
+use reth_primitives::{Address, Block, Bytes, Header, ReceiptWithBloom, B256, U256};
+use revm::InMemoryDB;
+use serde::{Deserialize, Serialize};
+use sp1_reth_primitives::{
+    db::InMemoryDBHelper,
+    mpt::{
+        is_not_included, keccak, parse_proof, resolve_nodes, shorten_node_path, MptNode,
+        MptNodeData, MptNodeReference,
+    },
+    processor::EvmProcessor,
+    SP1RethInput, StorageEntry,
+};
+
+use crate::utils::time_operation;
+
+/// The arguments of the `blocks` subcommand.
+#[derive(Args, Clone)]
+pub struct BlocksArgs {
+    #[command(subcommand)]
+    command: BlocksCommand,
+}
+
+#[derive(Subcommand, Clone)]
+enum BlocksCommand {
+    /// List the prepared blocks with their transaction count and gas used.
+    List,
+    /// Show the details of a prepared block.
+    Inspect { block: u64 },
+    /// Build a block input from a JSON-RPC dump and write it to `eval/blocks`.
+    Prepare {
+        /// The JSON file holding the RPC responses, see [`RpcDump`].
+        dump: PathBuf,
+        /// Overwrite the block input if it already exists.
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+/// The RPC responses needed to build an [`SP1RethInput`], stored locally so that no archive node
+/// is needed when preparing or benchmarking a block.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RpcDump {
+    /// The RLP encoded block (`debug_getRawBlock`).
+    block: Bytes,
+    /// The RLP encoded receipts of the block (`debug_getRawReceipts`).
+    receipts: Vec<Bytes>,
+    /// The RLP encoded ancestor headers (`debug_getRawHeader`), starting with the parent.
+    ancestors: Vec<Bytes>,
+    /// The account and storage proofs at the parent block (`eth_getProof`) of every account
+    /// touched by the block.
+    proofs: Vec<AccountProof>,
+    /// The same proofs at the block itself. They hold the nodes that remain after the block
+    /// deletes an account or a storage slot, which the guest needs to collapse the tries.
+    block_proofs: Vec<AccountProof>,
+    /// The code of every contract called by the block (`eth_getCode`).
+    codes: Vec<Bytes>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AccountProof {
+    address: Address,
+    account_proof: Vec<Bytes>,
+    storage_hash: B256,
+    storage_proof: Vec<StorageProof>,
+}
+
+#[derive(Deserialize)]
+struct StorageProof {
+    key: U256,
+    proof: Vec<Bytes>,
+}
+
+/// Summary statistics of a block input, stored next to the input in `eval/blocks/<block>.json`.
+#[derive(Serialize, Deserialize)]
+pub struct BlockStats {
+    pub block_number: u64,
+    pub transactions: usize,
+    pub gas_used: u64,
+    /// The number of accounts in the parent state.
+    pub accounts: usize,
+    /// The number of storage slots in the parent state.
+    pub storage_slots: usize,
+    /// The total size of the contract code in bytes.
+    pub code_size: usize,
+}
+
+impl BlockStats {
+    /// Computes the statistics of a block input, given the header that results from executing it.
+    fn new(input: &SP1RethInput, header: &Header) -> Self {
+        BlockStats {
+            block_number: header.number,
+            transactions: input.transactions.len(),
+            gas_used: header.gas_used,
+            accounts: input.parent_storage.len(),
+            storage_slots: input.parent_storage.values().map(|(_, slots)| slots.len()).sum(),
+            code_size: input.contracts.iter().map(|code| code.len()).sum(),
+        }
+    }
+}
+
+/// Returns the directory holding the prepared block inputs.
+pub fn blocks_dir() -> PathBuf {
+    let current_dir = std::env::current_dir().expect("Failed to get current working directory");
+    current_dir.join("eval").join("blocks")
+}
+
+/// Returns the numbers of all prepared blocks, in ascending order.
+pub fn list_blocks() -> Vec<u64> {
+    let blocks_dir = blocks_dir();
+    let mut blocks: Vec<u64> = fs::read_dir(&blocks_dir)
+        .unwrap_or_else(|_| panic!("Failed to read blocks directory: {:?}", blocks_dir))
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "bin" {
+                return None;
+            }
+            path.file_stem()?.to_str()?.parse().ok()
+        })
+        .collect();
+    blocks.sort();
+    blocks
+}
+
+/// Reads a prepared block input, if it exists.
+pub fn read_block(block_number: u64) -> Option<SP1RethInput> {
+    let bytes = fs::read(blocks_dir().join(format!("{}.bin", block_number))).ok()?;
+    Some(bincode::deserialize(&bytes).expect("Unable to deserialize input"))
+}
+
+/// Reads the statistics of a prepared block. They are computed when the block is prepared; for
+/// blocks prepared before the statistics were stored, the block is executed natively once and the
+/// statistics are written next to the input.
+pub fn block_stats(block_number: u64) -> BlockStats {
+    let path = blocks_dir().join(format!("{}.json", block_number));
+    if let Ok(json) = fs::read(&path) {
+        return serde_json::from_slice(&json).expect("Unable to parse block stats");
+    }
+    let input =
+        read_block(block_number).unwrap_or_else(|| panic!("Block {} not found", block_number));
+    let stats = BlockStats::new(&input, &execute_block(&input));
+    write_block_stats(&stats);
+    stats
+}
+
+fn write_block_stats(stats: &BlockStats) {
+    let path = blocks_dir().join(format!("{}.json", stats.block_number));
+    fs::write(path, serde_json::to_string_pretty(stats).unwrap()).unwrap();
+}
+
+/// Executes the block natively and returns the resulting header.
+fn execute_block(input: &SP1RethInput) -> Header {
+    let mut input = input.clone();
+    let db = InMemoryDB::initialize(&mut input).unwrap();
+    let mut executor = EvmProcessor::<InMemoryDB> { input, db: Some(db), header: None };
+    executor.initialize();
+    executor.execute();
+    executor.finalize();
+    executor.header.unwrap()
+}
+
+pub fn run(args: &BlocksArgs) {
+    match &args.command {
+        BlocksCommand::List => {
+            println!(
+                "{:>10} {:>6} {:>12} {:>9} {:>9} {:>10}",
+                "block", "txs", "gas used", "accounts", "slots", "code size"
+            );
+            for block_number in list_blocks() {
+                let stats = block_stats(block_number);
+                println!(
+                    "{:>10} {:>6} {:>12} {:>9} {:>9} {:>10}",
+                    block_number,
+                    stats.transactions,
+                    stats.gas_used,
+                    stats.accounts,
+                    stats.storage_slots,
+                    stats.code_size
+                );
+            }
+        }
+        BlocksCommand::Inspect { block } => {
+            let input = read_block(*block).unwrap_or_else(|| panic!("Block {} not found", block));
+            let stats = block_stats(*block);
+            let (_, execution_duration) = time_operation(|| execute_block(&input));
+            let input_size =
+                fs::metadata(blocks_dir().join(format!("{}.bin", block))).unwrap().len();
+            println!("block:              {}", stats.block_number);
+            println!("parent hash:        {}", input.parent_header.hash_slow());
+            println!("transactions:       {}", stats.transactions);
+            println!("withdrawals:        {}", input.withdrawals.len());
+            println!("gas used:           {}", stats.gas_used);
+            println!("gas limit:          {}", input.gas_limit);
+            println!("ancestor headers:   {}", input.ancestor_headers.len());
+            println!("accounts:           {}", stats.accounts);
+            println!("storage slots:      {}", stats.storage_slots);
+            println!("contracts:          {}", input.contracts.len());
+            println!("code size:          {}", stats.code_size);
+            println!("input size:         {}", input_size);
+            println!("native execution:   {:?}", execution_duration);
+        }
+        BlocksCommand::Prepare { dump, force } => {
+            let (input, stats) = prepare_block(dump);
+            let path = blocks_dir().join(format!("{}.bin", stats.block_number));
+            if path.exists() && !force {
+                panic!("Block {} already exists, pass --force to overwrite it", stats.block_number);
+            }
+            fs::write(&path, bincode::serialize(&input).unwrap()).unwrap();
+            write_block_stats(&stats);
+            println!("wrote {}", path.display());
+        }
+    }
+}
+
+/// Builds a block input from a JSON-RPC dump. The block is executed natively and the resulting
+/// header checked against the block, so an incomplete dump fails here rather than in the guest.
+fn prepare_block(path: &Path) -> (SP1RethInput, BlockStats) {
+    let file = fs::File::open(path).unwrap_or_else(|_| panic!("Failed to open dump: {:?}", path));
+    let dump: RpcDump = serde_json::from_reader(file).expect("Unable to parse RPC dump");
+
+    let block = Block::decode(&mut dump.block.as_ref()).expect("Invalid block RLP");
+    let mut ancestors = dump
+        .ancestors
+        .iter()
+        .map(|rlp| Header::decode(&mut rlp.as_ref()).expect("Invalid header RLP"));
+    let parent_header = ancestors.next().expect("The parent header is required");
+    assert_eq!(
+        block.header.parent_hash,
+        parent_header.hash_slow(),
+        "The first ancestor is not the parent"
+    );
+
+    // Check the dump against the receipts before building the input.
+    let receipts: Vec<ReceiptWithBloom> = dump
+        .receipts
+        .iter()
+        .map(|rlp| ReceiptWithBloom::decode(&mut rlp.as_ref()).expect("Invalid receipt RLP"))
+        .collect();
+    assert_eq!(receipts.len(), block.body.len(), "Receipt count does not match the block");
+    let gas_used = receipts.last().map_or(0, |r| r.receipt.cumulative_gas_used);
+    assert_eq!(gas_used, block.header.gas_used, "Receipts do not match the block gas used");
+
+    let (parent_state_trie, parent_storage) =
+        proofs_to_tries(parent_header.state_root, &dump.proofs, &dump.block_proofs);
+
+    let input = SP1RethInput {
+        beneficiary: block.header.beneficiary,
+        gas_limit: U256::from(block.header.gas_limit),
+        timestamp: U256::from(block.header.timestamp),
+        extra_data: block.header.extra_data.clone(),
+        mix_hash: block.header.mix_hash,
+        parent_state_trie,
+        parent_storage,
+        contracts: dump.codes,
+        parent_header,
+        ancestor_headers: ancestors.collect(),
+        transactions: block.body.clone(),
+        withdrawals: block.withdrawals.clone().unwrap_or_default().to_vec(),
+    };
+
+    let header = execute_block(&input);
+    assert_eq!(header.state_root, block.header.state_root, "State root does not match the block");
+    assert_eq!(header.gas_used, block.header.gas_used, "Gas used does not match the block");
+    assert_eq!(header.hash_slow(), block.header.hash_slow(), "Header does not match the block");
+
+    let stats = BlockStats::new(&input, &header);
+    (input, stats)
+}
+
+/// Builds the partial state trie and the partial storage tries from the `eth_getProof` responses
+/// at the parent block, adding the nodes of the responses at the block that deletions need.
+fn proofs_to_tries(
+    state_root: B256,
+    proofs: &[AccountProof],
+    block_proofs: &[AccountProof],
+) -> (MptNode, HashMap<Address, StorageEntry>) {
+    let block_proofs: HashMap<Address, &AccountProof> =
+        block_proofs.iter().map(|proof| (proof.address, proof)).collect();
+
+    // Collect all account proof nodes and resolve the trie below the state root.
+    let mut state_nodes = HashMap::new();
+    let mut state_root_node = MptNode::default();
+    for proof in proofs {
+        for rlp in &proof.account_proof {
+            let node = MptNode::decode(rlp).expect("Invalid account proof node");
+            if node.hash() == state_root {
+                state_root_node = node.clone();
+            }
+            state_nodes.insert(node.reference(), node);
+        }
+        if let Some(block_proof) = block_proofs.get(&proof.address) {
+            add_orphaned_leaves(proof.address, &block_proof.account_proof, &mut state_nodes);
+        }
+    }
+    let state_trie = resolve_nodes(&state_root_node, &state_nodes);
+    assert_eq!(state_trie.hash(), state_root, "Account proofs do not match the state root");
+
+    let mut storage = HashMap::new();
+    for proof in proofs {
+        // Without any slot proofs the storage trie is only its root, as the block doesn't touch
+        // the storage of the account.
+        if proof.storage_proof.is_empty() {
+            let storage_trie = MptNodeData::Digest(proof.storage_hash).into();
+            storage.insert(proof.address, (storage_trie, vec![]));
+            continue;
+        }
+
+        let mut storage_nodes: HashMap<MptNodeReference, MptNode> = HashMap::new();
+        let mut storage_root_node = MptNode::default();
+        for rlp in proof.storage_proof.iter().flat_map(|slot| &slot.proof) {
+            let node = MptNode::decode(rlp).expect("Invalid storage proof node");
+            if node.hash() == proof.storage_hash {
+                storage_root_node = node.clone();
+            }
+            storage_nodes.insert(node.reference(), node);
+        }
+        if let Some(block_proof) = block_proofs.get(&proof.address) {
+            for slot in &block_proof.storage_proof {
+                add_orphaned_leaves(slot.key.to_be_bytes::<32>(), &slot.proof, &mut storage_nodes);
+            }
+        }
+        let storage_trie = resolve_nodes(&storage_root_node, &storage_nodes);
+        assert_eq!(
+            storage_trie.hash(),
+            proof.storage_hash,
+            "Storage proofs do not match the storage root of {}",
+            proof.address
+        );
+        let slots = proof.storage_proof.iter().map(|slot| slot.key).collect();
+        storage.insert(proof.address, (storage_trie, slots));
+    }
+
+    (state_trie, storage)
+}
+
+/// Adds the leaf next to a key that the block deleted, taken from its proof of exclusion at the
+/// block. Deleting the key collapses a branch into this leaf with a shorter path, so all of its
+/// shortened forms are added.
+fn add_orphaned_leaves(
+    key: impl AsRef<[u8]>,
+    proof: &[Bytes],
+    nodes: &mut HashMap<MptNodeReference, MptNode>,
+) {
+    if proof.is_empty() {
+        return;
+    }
+    let proof_nodes = parse_proof(proof).expect("Invalid proof node at the block");
+    if is_not_included(&keccak(key), &proof_nodes).expect("Invalid proof at the block") {
+        for node in shorten_node_path(proof_nodes.last().unwrap()) {
+            nodes.insert(node.reference(), node);
+        }
+    }
+}
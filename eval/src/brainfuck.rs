@@ -0,0 +1,424 @@
+//! Reading, encoding and compiling the brainfuck benchmark programs.
+
+use std::{
+    collections::VecDeque,
+    fs,
+    path::{Path, PathBuf},
+};
+
+#[cfg(any(test, feature = "powdr-plonky3", feature = "powdr-estark"))]
+use brainfuck_ast::Node;
+pub use brainfuck_ast::Program;
+
+use crate::{BrainfuckCase, EvalArgs};
+
+/// Reads and parses a brainfuck program, rejecting malformed programs before any proving starts.
+fn read_brainfuck_program(path: &Path) -> Program {
+    let content = fs::read_to_string(path).expect("error reading brainfuck program");
+    Program::parse(&content).unwrap_or_else(|err| panic!("{}:{}", path.display(), err))
+}
+
+fn read_brainfuck_inputs(path: &Path) -> VecDeque<u32> {
+    let content = fs::read_to_string(path).expect("error reading brainfuck input file");
+    content
+        .split(',')
+        .map(|x| x.trim())
+        .filter(|x| !x.is_empty())
+        .map(|x| x.parse::<u32>().unwrap())
+        .collect()
+}
+
+/// Returns the directory of a corpus program, holding `program.bf`, the optional `input.in` and
+/// the expected output `output.txt`.
+fn corpus_dir(case: BrainfuckCase) -> PathBuf {
+    PathBuf::from("programs/brainfuck/corpus").join(case.to_string())
+}
+
+pub fn get_brainfuck_input(args: &EvalArgs) -> (Program, VecDeque<u32>) {
+    if let Some(case) = args.bf_case {
+        let dir = corpus_dir(case);
+        let input = dir.join("input.in");
+        let input = if input.exists() { read_brainfuck_inputs(&input) } else { Default::default() };
+        return (read_brainfuck_program(&dir.join("program.bf")), input);
+    }
+    match &args.program_inputs[..] {
+        [program] => (read_brainfuck_program(program.as_ref()), Default::default()),
+        [program, input] => {
+            (read_brainfuck_program(program.as_ref()), read_brainfuck_inputs(input.as_ref()))
+        }
+        _ => panic!("Brainfuck interpreter requires a program and possibly inputs"),
+    }
+}
+
+/// Checks the output of a brainfuck run against the expected output of the corpus program, if
+/// one was selected.
+pub fn check_brainfuck_output(args: &EvalArgs, output: &[u8]) {
+    let Some(case) = args.bf_case else {
+        return;
+    };
+    let expected = fs::read(corpus_dir(case).join("output.txt"))
+        .expect("error reading expected brainfuck output");
+    assert!(
+        output == expected,
+        "output of {} does not match the expected output:\n{}\nexpected:\n{}",
+        case,
+        String::from_utf8_lossy(output),
+        String::from_utf8_lossy(&expected)
+    );
+    println!("output of {} matches the expected output", case);
+}
+
+/// Encodes a brainfuck program for `brainfuck_vm.asm`, padded with zeros to a multiple of 8
+/// words, as the program commitment hashes it in blocks of 8.
+#[cfg(any(feature = "powdr-plonky3", feature = "powdr-estark"))]
+pub fn encode_brainfuck_vm_program(program: &Program) -> Vec<u32> {
+    let mut opcodes = program.encode();
+    opcodes.resize(opcodes.len().next_multiple_of(8), 0);
+    opcodes
+}
+
+/// Computes the program commitment exposed by `brainfuck_vm.asm`: the padded program is hashed
+/// with Poseidon in blocks of 8 words, starting from a state holding the program length.
+#[cfg(any(feature = "powdr-plonky3", feature = "powdr-estark"))]
+pub fn brainfuck_program_commitment<T: powdr_number::FieldElement>(program: &[u32]) -> [T; 4] {
+    let mut hash = [T::from(program.len() as u64), T::from(0), T::from(0), T::from(0)];
+    for block in program.chunks(8) {
+        let mut state = [T::from(0); 12];
+        for (s, word) in state.iter_mut().zip(block) {
+            *s = T::from(*word);
+        }
+        state[8..].copy_from_slice(&hash);
+        hash = powdr_riscv_executor::poseidon_gl::poseidon_gl(&state);
+    }
+    hash
+}
+
+/// Pushes the asm for a `.`.
+#[cfg(any(feature = "powdr-plonky3", feature = "powdr-estark"))]
+fn emit_write(asm: &mut Vec<String>) {
+    asm.push("data <== mload();".to_string());
+    asm.push(
+        "data <=X= ${ Query::Output(1, std::convert::int(std::prover::eval(data))) };".to_string(),
+    );
+}
+
+/// Pushes the asm for a loop, with `emit_body` pushing the asm of its body.
+#[cfg(any(feature = "powdr-plonky3", feature = "powdr-estark"))]
+fn emit_loop(
+    asm: &mut Vec<String>,
+    loop_counter: &mut usize,
+    emit_body: impl FnOnce(&mut Vec<String>, &mut usize),
+) {
+    let label_true = format!("loop_true_{loop_counter}");
+    let label_false = format!("loop_false_{loop_counter}");
+    *loop_counter += 1;
+    asm.push(format!("{label_true}:"));
+    asm.push("data <== mload();".to_string());
+    asm.push(format!("branch_if_zero data, {label_false};"));
+    emit_body(asm, loop_counter);
+    asm.push(format!("jump {label_true};"));
+    asm.push(format!("{label_false}:"))
+}
+
+/// compile brainfuck into powdr asm instructions for the custom brainfuck vm
+#[cfg(any(feature = "powdr-plonky3", feature = "powdr-estark"))]
+pub fn compile_brainfuck(program: &Program) -> String {
+    fn emit(nodes: &[Node], asm: &mut Vec<String>, loop_counter: &mut usize) {
+        for node in nodes {
+            // match each brainfuck operation
+            match node {
+                Node::Right => asm.push("inc_dp;".to_string()),
+                Node::Left => asm.push("dec_dp;".to_string()),
+                Node::Inc => asm.push("inc_cell;".to_string()),
+                Node::Dec => asm.push("dec_cell;".to_string()),
+                Node::Read => asm.push("read in_remaining;".to_string()),
+                Node::Write => emit_write(asm),
+                Node::Loop(body) => {
+                    emit_loop(asm, loop_counter, |asm, loop_counter| emit(body, asm, loop_counter));
+                }
+            }
+        }
+    }
+
+    let mut asm = vec![];
+    emit(&program.nodes, &mut asm, &mut 0);
+    asm.push("return;".to_string());
+    asm.join("\n        ")
+}
+
+/// An operation of the optimising brainfuck compiler, with runs and loop idioms folded.
+#[cfg(any(test, feature = "powdr-plonky3", feature = "powdr-estark"))]
+#[derive(Debug, Clone, PartialEq)]
+enum BfOp {
+    /// Add to the current cell.
+    Add(i64),
+    /// Move the data pointer.
+    Move(i64),
+    /// Set the current cell to zero (`[-]`).
+    Clear,
+    /// Add the current cell times `factor` to the cell at `offset` from the data pointer.
+    MulAdd {
+        offset: i64,
+        factor: i64,
+    },
+    Read,
+    Write,
+    Loop(Vec<BfOp>),
+}
+
+/// Lowers brainfuck nodes into [`BfOp`]s, folding runs of `+-` and `<>`.
+#[cfg(any(test, feature = "powdr-plonky3", feature = "powdr-estark"))]
+fn lower_bf_ops(nodes: &[Node]) -> Vec<BfOp> {
+    let mut ops = vec![];
+    for node in nodes {
+        let op = match node {
+            Node::Right => BfOp::Move(1),
+            Node::Left => BfOp::Move(-1),
+            Node::Inc => BfOp::Add(1),
+            Node::Dec => BfOp::Add(-1),
+            Node::Read => BfOp::Read,
+            Node::Write => BfOp::Write,
+            Node::Loop(body) => {
+                ops.extend(optimize_loop(lower_bf_ops(body)));
+                continue;
+            }
+        };
+        match (ops.last_mut(), op) {
+            (Some(BfOp::Add(k)), BfOp::Add(n)) => *k += n,
+            (Some(BfOp::Move(k)), BfOp::Move(n)) => *k += n,
+            (_, op) => ops.push(op),
+        }
+        if matches!(ops.last(), Some(BfOp::Add(0) | BfOp::Move(0))) {
+            ops.pop();
+        }
+    }
+    ops
+}
+
+/// Replaces clear loops (`[-]`) and multiply-move loops (e.g. `[->++>+<<]`) by straight-line
+/// operations. These are no-ops on a zero cell, so they need no zero check.
+#[cfg(any(test, feature = "powdr-plonky3", feature = "powdr-estark"))]
+fn optimize_loop(body: Vec<BfOp>) -> Vec<BfOp> {
+    // a multiply-move loop only adds and moves, returns to its starting cell and decrements it
+    let mut offset = 0;
+    let mut deltas: Vec<(i64, i64)> = vec![];
+    for op in &body {
+        match op {
+            BfOp::Move(k) => offset += k,
+            BfOp::Add(k) => match deltas.iter_mut().find(|(o, _)| *o == offset) {
+                Some((_, delta)) => *delta += k,
+                None => deltas.push((offset, *k)),
+            },
+            _ => return vec![BfOp::Loop(body)],
+        }
+    }
+    if offset != 0 || !deltas.contains(&(0, -1)) {
+        return vec![BfOp::Loop(body)];
+    }
+    deltas
+        .into_iter()
+        .filter(|&(offset, factor)| offset != 0 && factor != 0)
+        .map(|(offset, factor)| BfOp::MulAdd { offset, factor })
+        .chain(std::iter::once(BfOp::Clear))
+        .collect()
+}
+
+/// compile brainfuck into powdr asm instructions for the custom brainfuck vm, folding runs into
+/// `add_cell k`/`move_dp k` and loop idioms into `clear_cell`/`mul_add_cell offset, factor`
+#[cfg(any(feature = "powdr-plonky3", feature = "powdr-estark"))]
+pub fn compile_brainfuck_optimized(program: &Program) -> String {
+    fn emit(ops: &[BfOp], asm: &mut Vec<String>, loop_counter: &mut usize) {
+        for op in ops {
+            match op {
+                BfOp::Add(1) => asm.push("inc_cell;".to_string()),
+                BfOp::Add(-1) => asm.push("dec_cell;".to_string()),
+                BfOp::Add(k) => asm.push(format!("add_cell {k};")),
+                BfOp::Move(1) => asm.push("inc_dp;".to_string()),
+                BfOp::Move(-1) => asm.push("dec_dp;".to_string()),
+                BfOp::Move(k) => asm.push(format!("move_dp {k};")),
+                BfOp::Clear => asm.push("clear_cell;".to_string()),
+                BfOp::MulAdd { offset, factor } => {
+                    asm.push(format!("mul_add_cell {offset}, {factor};"))
+                }
+                BfOp::Read => asm.push("read in_remaining;".to_string()),
+                BfOp::Write => emit_write(asm),
+                BfOp::Loop(body) => {
+                    emit_loop(asm, loop_counter, |asm, loop_counter| emit(body, asm, loop_counter));
+                }
+            }
+        }
+    }
+
+    let mut asm = vec![];
+    emit(&lower_bf_ops(&program.nodes), &mut asm, &mut 0);
+    asm.push("return;".to_string());
+    asm.join("\n        ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A brainfuck machine whose cells wrap around at `modulus`. The optimizations only rely on
+    /// the cells wrapping around, as they also do in the field of the powdr VM.
+    struct Machine {
+        modulus: i64,
+        tape: Vec<i64>,
+        dp: usize,
+        input: VecDeque<u32>,
+        output: Vec<u8>,
+    }
+
+    impl Machine {
+        fn new(modulus: i64, input: &[u32]) -> Self {
+            let input = input.iter().copied().collect();
+            Machine { modulus, tape: vec![0; 30000], dp: 0, input, output: vec![] }
+        }
+
+        fn add(&mut self, offset: i64, value: i64) {
+            let cell = &mut self.tape[(self.dp as i64 + offset) as usize];
+            *cell = (*cell + value).rem_euclid(self.modulus);
+        }
+
+        fn read(&mut self) {
+            // -1 on EOF
+            let value = self.input.pop_front().map_or(-1, |i| i as i64);
+            self.tape[self.dp] = value.rem_euclid(self.modulus);
+        }
+
+        fn run_nodes(&mut self, nodes: &[Node]) {
+            for node in nodes {
+                match node {
+                    Node::Right => self.dp += 1,
+                    Node::Left => self.dp -= 1,
+                    Node::Inc => self.add(0, 1),
+                    Node::Dec => self.add(0, -1),
+                    Node::Read => self.read(),
+                    Node::Write => self.output.push(self.tape[self.dp] as u8),
+                    Node::Loop(body) => {
+                        while self.tape[self.dp] != 0 {
+                            self.run_nodes(body);
+                        }
+                    }
+                }
+            }
+        }
+
+        fn run_ops(&mut self, ops: &[BfOp]) {
+            for op in ops {
+                match op {
+                    BfOp::Add(k) => self.add(0, *k),
+                    BfOp::Move(k) => self.dp = (self.dp as i64 + k) as usize,
+                    BfOp::Clear => self.tape[self.dp] = 0,
+                    BfOp::MulAdd { offset, factor } => {
+                        self.add(*offset, self.tape[self.dp] * factor)
+                    }
+                    BfOp::Read => self.read(),
+                    BfOp::Write => self.output.push(self.tape[self.dp] as u8),
+                    BfOp::Loop(body) => {
+                        while self.tape[self.dp] != 0 {
+                            self.run_ops(body);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Runs a program naively and optimized, checks that both end with the same tape and output
+    /// and returns the output.
+    fn run_both(modulus: i64, program: &Program, input: &[u32]) -> Vec<u8> {
+        let mut naive = Machine::new(modulus, input);
+        naive.run_nodes(&program.nodes);
+        let mut optimized = Machine::new(modulus, input);
+        optimized.run_ops(&lower_bf_ops(&program.nodes));
+        assert_eq!(naive.dp, optimized.dp, "data pointers differ");
+        assert!(naive.tape == optimized.tape, "tapes differ");
+        assert_eq!(naive.output, optimized.output, "outputs differ");
+        naive.output
+    }
+
+    /// Runs a small program with 8-bit cells, so that wrapping around is cheap.
+    fn run_small(source: &str, input: &[u32]) -> Vec<u8> {
+        run_both(256, &parse(source), input)
+    }
+
+    fn parse(source: &str) -> Program {
+        Program::parse(source).unwrap()
+    }
+
+    #[test]
+    fn folds_runs() {
+        let ops = lower_bf_ops(&parse(">>+++--<>>-+").nodes);
+        assert_eq!(ops, vec![BfOp::Move(2), BfOp::Add(1), BfOp::Move(1)]);
+        run_small(">>+++--<>>-+", &[]);
+    }
+
+    #[test]
+    fn clear_loop() {
+        let program = parse("+++++[-]>++[-]+.");
+        assert_eq!(
+            lower_bf_ops(&program.nodes),
+            vec![
+                BfOp::Add(5),
+                BfOp::Clear,
+                BfOp::Move(1),
+                BfOp::Add(2),
+                BfOp::Clear,
+                BfOp::Add(1),
+                BfOp::Write
+            ]
+        );
+        assert_eq!(run_small("+++++[-]>++[-]+.", &[]), vec![1]);
+    }
+
+    #[test]
+    fn multiply_loops() {
+        // a positive and a negative offset, with factors of both signs
+        let program = parse(">>+++++[-<<++>>>---<]<<.>>>.");
+        assert_eq!(
+            lower_bf_ops(&program.nodes)[2..4],
+            [BfOp::MulAdd { offset: -2, factor: 2 }, BfOp::MulAdd { offset: 1, factor: -3 }]
+        );
+        assert_eq!(run_small(">>+++++[-<<++>>>---<]<<.>>>.", &[]), vec![10, 241]);
+    }
+
+    #[test]
+    fn loops_that_are_not_multiply_loops() {
+        // the loop moves the data pointer, decrements by 2 or does I/O
+        run_small("+>+>+<<[>]+.", &[]);
+        run_small("++++[--]++++[-->+<]", &[]);
+        run_small(",[.,]", &[104, 105, 0]);
+    }
+
+    #[test]
+    fn nested_loops() {
+        let program = parse("++[>+++[>++<-]<-]>>.");
+        assert!(matches!(lower_bf_ops(&program.nodes)[1], BfOp::Loop(_)));
+        assert_eq!(run_small("++[>+++[>++<-]<-]>>.", &[]), vec![12]);
+    }
+
+    #[test]
+    fn wrapping() {
+        // -1 wraps to 255, so the clear and multiply loops run 255 times
+        assert_eq!(run_small("-[>+<-]>.", &[]), vec![255]);
+        assert_eq!(run_small("-[>++<-]>.", &[]), vec![254]);
+        assert_eq!(run_small(",[-]+.", &[]), vec![1]);
+    }
+
+    #[test]
+    fn corpus() {
+        let corpus = Path::new(env!("CARGO_MANIFEST_DIR")).join("../programs/brainfuck/corpus");
+        for case in ["hello-world", "rot13", "squares", "self-interpreter", "mandelbrot"] {
+            let dir = corpus.join(case);
+            let input = dir.join("input.in");
+            let input: Vec<u32> =
+                if input.exists() { read_brainfuck_inputs(&input).into() } else { vec![] };
+            // the programs never leave the 32-bit range, so they run as with unbounded cells
+            let program = read_brainfuck_program(&dir.join("program.bf"));
+            let output = run_both(1 << 32, &program, &input);
+            let expected = fs::read(dir.join("output.txt")).unwrap();
+            assert!(output == expected, "unexpected output of {}", case);
+        }
+    }
+}
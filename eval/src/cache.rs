@@ -0,0 +1,497 @@
+//! An on-disk cache of the prover setups, such as SP1's proving key and powdr's fixed columns,
+//! enabled with `--setup-cache DIR`.
+//!
+//! An entry is keyed by the hash of the program (the ELF or the powdr asm), the prover, the hash
+//! function, the shard size and the backend version, so it is never reused for a different setup.
+//! It is stored as `<dir>/<prover>/<key>.bin`, next to a `<key>.json` recording the duration of the
+//! setup that produced it. A run that hits the cache thus reports both the cold setup, recorded
+//! when the entry was written, and the warm setup, the time to read the entry.
+//!
+//! The directory also caches builds that are not timed, such as the powdr asm compiled from a
+//! guest crate, as `<dir>/<kind>/<key>.bin`.
+
+use std::{
+    collections::BTreeSet,
+    fmt, fs,
+    io::{BufReader, BufWriter, Write},
+    iter,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::EvalArgs;
+
+/// Whether a setup went through the cache.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CacheStatus {
+    /// There is no `--setup-cache`, or nothing of the prover's setup is cached.
+    #[default]
+    Off,
+    /// The setup was computed and written to the cache.
+    Miss,
+    /// The setup was read from the cache.
+    Hit,
+}
+
+impl fmt::Display for CacheStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CacheStatus::Off => "off",
+            CacheStatus::Miss => "miss",
+            CacheStatus::Hit => "hit",
+        })
+    }
+}
+
+/// The durations of a setup.
+#[derive(Debug, Clone, Default)]
+pub struct SetupDurations {
+    /// The duration of computing the setup, measured by this run or by the one that wrote the
+    /// cache entry.
+    pub cold: Duration,
+    /// The duration of reading the setup from the cache, on a hit.
+    pub warm: Option<Duration>,
+    pub status: CacheStatus,
+}
+
+impl SetupDurations {
+    /// Combines the durations of two parts of a setup. A part that is not cached is paid by cold
+    /// and warm setups alike, and the setup only hits the cache if every cached part does.
+    pub fn and(self, other: SetupDurations) -> SetupDurations {
+        let warm = |part: &SetupDurations| part.warm.unwrap_or(part.cold);
+        SetupDurations {
+            cold: self.cold + other.cold,
+            warm: (self.warm.is_some() || other.warm.is_some()).then(|| warm(&self) + warm(&other)),
+            status: match (self.status, other.status) {
+                (CacheStatus::Off, status) | (status, CacheStatus::Off) => status,
+                (CacheStatus::Hit, CacheStatus::Hit) => CacheStatus::Hit,
+                _ => CacheStatus::Miss,
+            },
+        }
+    }
+}
+
+/// The record of how a cache entry was produced.
+#[derive(Serialize, Deserialize)]
+struct EntryInfo {
+    program: String,
+    shard_size: u64,
+    backend_version: String,
+    cold_setup_duration: f64,
+}
+
+/// Hashes the length-prefixed parts of a key.
+fn entry_key(parts: &[&[u8]]) -> String {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update((part.len() as u64).to_le_bytes());
+        hasher.update(part);
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+/// Reads a cache entry, if there is one.
+fn read_entry<S: DeserializeOwned>(path: &Path) -> Option<S> {
+    let entry = fs::File::open(path).ok()?;
+    let result = bincode::deserialize_from(BufReader::new(entry))
+        .unwrap_or_else(|_| panic!("Failed to read cache entry {:?}", path));
+    Some(result)
+}
+
+/// Writes a cache entry to a temporary file first, so that an interrupted run leaves no partial
+/// entry.
+fn write_entry<S: Serialize>(path: &Path, result: &S) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    let tmp_path = path.with_extension("bin.tmp");
+    let mut writer = BufWriter::new(fs::File::create(&tmp_path).unwrap());
+    bincode::serialize_into(&mut writer, result).expect("Failed to write the cache entry");
+    writer.flush().unwrap();
+    fs::rename(&tmp_path, path).unwrap();
+}
+
+/// The directories of the crates a manifest depends on by path: the `path` of the entries of its
+/// dependency and build dependency tables, including those of its targets, and of its patches.
+fn path_dependencies(manifest: &toml::Table) -> Vec<PathBuf> {
+    fn table(value: Option<&toml::Value>) -> Option<&toml::Table> {
+        value.and_then(toml::Value::as_table)
+    }
+
+    let targets = table(manifest.get("target")).into_iter().flat_map(|targets| targets.values());
+    let mut tables = vec![];
+    for manifest in iter::once(manifest).chain(targets.filter_map(toml::Value::as_table)) {
+        tables.extend(table(manifest.get("dependencies")));
+        tables.extend(table(manifest.get("build-dependencies")));
+    }
+    if let Some(patches) = table(manifest.get("patch")) {
+        tables.extend(patches.values().filter_map(toml::Value::as_table));
+    }
+    tables
+        .into_iter()
+        .flat_map(|dependencies| dependencies.values())
+        .filter_map(|dependency| dependency.get("path")?.as_str())
+        .map(PathBuf::from)
+        .collect()
+}
+
+/// Hashes the contents of the crate at `path` in the workspace at `root`: the path and content of
+/// its files, outside of `target`, and those of the crates it depends on by path, which must be in
+/// the workspace too. The paths are relative to the root, so that every checkout of the workspace
+/// has the same digest, and a build of the crate can be cached until one of the files changes.
+pub fn crate_digest(root: &Path, path: &Path) -> Vec<u8> {
+    fn collect_files(dir: &Path, files: &mut BTreeSet<PathBuf>) {
+        let entries = fs::read_dir(dir).unwrap_or_else(|_| panic!("Failed to read {:?}", dir));
+        for entry in entries.map(|entry| entry.unwrap().path()) {
+            if entry.is_dir() {
+                if entry.file_name().is_some_and(|name| name != "target" && name != ".git") {
+                    collect_files(&entry, files);
+                }
+            } else {
+                files.insert(entry);
+            }
+        }
+    }
+
+    let root = root.canonicalize().unwrap_or_else(|_| panic!("Failed to find {:?}", root));
+    let mut crates = vec![root.join(path)];
+    let mut visited = BTreeSet::new();
+    let mut files = BTreeSet::new();
+    while let Some(dir) = crates.pop() {
+        let dir = dir.canonicalize().unwrap_or_else(|_| panic!("Failed to find {:?}", dir));
+        if !visited.insert(dir.clone()) {
+            continue;
+        }
+        collect_files(&dir, &mut files);
+        let manifest_path = dir.join("Cargo.toml");
+        let Ok(manifest) = fs::read_to_string(&manifest_path) else {
+            continue;
+        };
+        let manifest: toml::Table = toml::from_str(&manifest)
+            .unwrap_or_else(|err| panic!("Failed to parse {:?}: {err}", manifest_path));
+        crates.extend(
+            path_dependencies(&manifest).into_iter().map(|dependency| dir.join(dependency)),
+        );
+    }
+
+    let mut hasher = Sha256::new();
+    for file in files {
+        let content = fs::read(&file).unwrap_or_else(|_| panic!("Failed to read {:?}", file));
+        let name = file
+            .strip_prefix(&root)
+            .unwrap_or_else(|_| panic!("{:?} is outside of the workspace {:?}", file, root))
+            .to_string_lossy();
+        hasher.update((name.len() as u64).to_le_bytes());
+        hasher.update(name.as_bytes());
+        hasher.update((content.len() as u64).to_le_bytes());
+        hasher.update(&content);
+    }
+    hasher.finalize().to_vec()
+}
+
+/// Runs `build`, or reads its result from the `--setup-cache` directory, for builds that are not
+/// part of the measured setup. `kind` names the build, and `key` is everything its result depends
+/// on.
+pub fn cached_build<S: Serialize + DeserializeOwned>(
+    args: &EvalArgs,
+    kind: &str,
+    key: &[&[u8]],
+    build: impl FnOnce() -> S,
+) -> S {
+    let Some(cache_dir) = &args.setup_cache else {
+        return build();
+    };
+    let entry_path = cache_dir.join(kind).join(format!("{}.bin", entry_key(key)));
+    if let Some(result) = read_entry(&entry_path) {
+        println!("read the {kind} from {}", entry_path.display());
+        return result;
+    }
+    let result = build();
+    write_entry(&entry_path, &result);
+    println!("wrote the {kind} to {}", entry_path.display());
+    result
+}
+
+/// Runs `setup`, or reads its result from the `--setup-cache` directory. `program` is the ELF or
+/// asm being set up, and `backend_version` identifies the backend and any of its settings the
+/// setup depends on.
+pub fn cached_setup<S: Serialize + DeserializeOwned>(
+    args: &EvalArgs,
+    program: &[u8],
+    backend_version: &str,
+    setup: impl FnOnce() -> S,
+) -> (S, SetupDurations) {
+    let Some(cache_dir) = &args.setup_cache else {
+        let start = Instant::now();
+        let result = setup();
+        return (result, SetupDurations { cold: start.elapsed(), ..Default::default() });
+    };
+
+    let key = entry_key(&[
+        args.prover.to_string().as_bytes(),
+        args.hashfn.to_string().as_bytes(),
+        &args.shard_size.to_le_bytes(),
+        backend_version.as_bytes(),
+        program,
+    ]);
+    let dir = cache_dir.join(args.prover.to_string());
+    let entry_path = dir.join(format!("{key}.bin"));
+    let info_path = dir.join(format!("{key}.json"));
+
+    if let Ok(info) = fs::read(&info_path) {
+        let info: EntryInfo = serde_json::from_slice(&info).expect("Invalid setup cache entry");
+        let start = Instant::now();
+        let result = read_entry(&entry_path)
+            .unwrap_or_else(|| panic!("Missing setup cache entry {:?}", entry_path));
+        let warm = start.elapsed();
+        println!("read the setup from {}", entry_path.display());
+        let durations = SetupDurations {
+            cold: Duration::from_secs_f64(info.cold_setup_duration),
+            warm: Some(warm),
+            status: CacheStatus::Hit,
+        };
+        return (result, durations);
+    }
+
+    let start = Instant::now();
+    let result = setup();
+    let cold = start.elapsed();
+
+    write_entry(&entry_path, &result);
+    let info = EntryInfo {
+        program: args.program.to_string(),
+        shard_size: args.shard_size,
+        backend_version: backend_version.to_string(),
+        cold_setup_duration: cold.as_secs_f64(),
+    };
+    fs::write(&info_path, serde_json::to_string_pretty(&info).unwrap()).unwrap();
+    println!("wrote the setup to {}", entry_path.display());
+
+    (result, SetupDurations { cold, warm: None, status: CacheStatus::Miss })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+    use crate::{HashFnId, PowdrDegreeMode, PowdrLinker, ProgramId, ProverId};
+
+    /// The arguments of an SP1 run of `fibonacci`, with a setup cache in `cache_dir`.
+    fn args(cache_dir: &Path) -> EvalArgs {
+        EvalArgs {
+            program: ProgramId::Fibonacci,
+            prover: ProverId::SP1,
+            hashfn: HashFnId::Poseidon,
+            shard_size: 20,
+            filename: "test".to_string(),
+            powdr_no_continuations: false,
+            powdr_linker: PowdrLinker::Native,
+            powdr_degree_mode: PowdrDegreeMode::Vadcop,
+            execute_only: false,
+            core_only: false,
+            prover_opts: vec![],
+            prover_opts_file: None,
+            save_proofs: None,
+            setup_cache: Some(cache_dir.to_path_buf()),
+            bf_case: None,
+            program_inputs: vec![],
+        }
+    }
+
+    /// A fresh cache directory for a test.
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("setup_cache_{}_{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn setup_cache_keys() {
+        let dir = cache_dir("keys");
+        let setups = Cell::new(0);
+        let setup = |args: &EvalArgs, program: &[u8], version: &str| {
+            let (result, durations) = cached_setup(args, program, version, || {
+                setups.set(setups.get() + 1);
+                program.len()
+            });
+            assert_eq!(result, program.len());
+            durations.status
+        };
+
+        let args = args(&dir);
+        assert_eq!(setup(&args, b"elf", "v1"), CacheStatus::Miss);
+        assert_eq!(setup(&args, b"elf", "v1"), CacheStatus::Hit);
+        assert_eq!(setups.get(), 1);
+        // any part of the key that differs is a different setup
+        assert_eq!(setup(&args, b"other elf", "v1"), CacheStatus::Miss);
+        assert_eq!(setup(&args, b"elf", "v2"), CacheStatus::Miss);
+        assert_eq!(
+            setup(&EvalArgs { shard_size: 21, ..args.clone() }, b"elf", "v1"),
+            CacheStatus::Miss
+        );
+        assert_eq!(
+            setup(&EvalArgs { hashfn: HashFnId::Blake3, ..args.clone() }, b"elf", "v1"),
+            CacheStatus::Miss
+        );
+        assert_eq!(
+            setup(&EvalArgs { prover: ProverId::Risc0, ..args.clone() }, b"elf", "v1"),
+            CacheStatus::Miss
+        );
+        assert_eq!(setups.get(), 6);
+        // the program name is only recorded, the program itself is in the key
+        assert_eq!(
+            setup(&EvalArgs { program: ProgramId::Loop, ..args.clone() }, b"elf", "v1"),
+            CacheStatus::Hit
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn hits_report_the_cold_setup() {
+        let dir = cache_dir("durations");
+        let args = args(&dir);
+        let (_, miss) =
+            cached_setup(&args, b"elf", "v1", || std::thread::sleep(Duration::from_millis(50)));
+        let (_, hit) = cached_setup::<()>(&args, b"elf", "v1", || unreachable!());
+        assert!(miss.warm.is_none());
+        assert!(miss.cold >= Duration::from_millis(50));
+        // the cold setup is read back from the entry, rounded through its JSON record
+        assert!(hit.cold.abs_diff(miss.cold) < Duration::from_micros(1));
+        assert!(hit.warm.is_some());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn without_a_cache_setups_are_not_cached() {
+        let args = EvalArgs { setup_cache: None, ..args(Path::new("unused")) };
+        let (result, durations) = cached_setup(&args, b"elf", "v1", || 1);
+        assert_eq!(result, 1);
+        assert_eq!(durations.status, CacheStatus::Off);
+        assert!(!Path::new("unused").exists());
+    }
+
+    /// Writes the files of a crate in a test workspace.
+    fn write_crate(root: &Path, path: &str, files: &[(&str, &str)]) {
+        for (name, content) in files {
+            let file = root.join(path).join(name);
+            fs::create_dir_all(file.parent().unwrap()).unwrap();
+            fs::write(file, content).unwrap();
+        }
+    }
+
+    /// A workspace with a guest crate depending by path on a library, for a target, and on a
+    /// build helper, next to an unrelated crate.
+    fn workspace(name: &str) -> PathBuf {
+        let root = cache_dir(name);
+        let guest = r#"
+            [package]
+            name = "guest"
+            description = 'mentions path = "../unrelated" in its text'
+
+            [target.'cfg(target_os = "zkvm")'.dependencies]
+            lib = { path = "../lib" }
+
+            [build-dependencies.build]
+            path = "../build"
+        "#;
+        write_crate(&root, "guest", &[("Cargo.toml", guest), ("src/main.rs", "fn main() {}")]);
+        write_crate(&root, "lib", &[("Cargo.toml", "[package]"), ("src/lib.rs", "")]);
+        write_crate(&root, "build", &[("Cargo.toml", "[package]"), ("src/lib.rs", "")]);
+        write_crate(&root, "unrelated", &[("Cargo.toml", "[package]"), ("src/lib.rs", "")]);
+        root
+    }
+
+    #[test]
+    fn crate_digests_follow_path_dependencies() {
+        let root = workspace("digest_dependencies");
+        let digest = || crate_digest(&root, Path::new("guest"));
+        let before = digest();
+        assert_eq!(digest(), before);
+
+        // build outputs and crates that are not dependencies are left out
+        write_crate(&root, "guest", &[("target/release/guest", "elf")]);
+        write_crate(&root, "unrelated", &[("src/lib.rs", "pub fn f() {}")]);
+        assert_eq!(digest(), before);
+
+        let mut digests = BTreeSet::from([before]);
+        write_crate(&root, "guest", &[("src/main.rs", "fn main() { }")]);
+        assert!(digests.insert(digest()));
+        write_crate(&root, "lib", &[("src/lib.rs", "pub fn f() {}")]);
+        assert!(digests.insert(digest()));
+        write_crate(&root, "build", &[("src/lib.rs", "pub fn f() {}")]);
+        assert!(digests.insert(digest()));
+        // a renamed file is a different crate
+        fs::rename(root.join("lib/src/lib.rs"), root.join("lib/src/other.rs")).unwrap();
+        assert!(digests.insert(digest()));
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn crate_digests_are_the_same_in_every_checkout() {
+        let root = workspace("digest_checkout");
+        let other = workspace("digest_other_checkout");
+        assert_eq!(
+            crate_digest(&root, Path::new("guest")),
+            crate_digest(&other, Path::new("guest"))
+        );
+        fs::remove_dir_all(&root).unwrap();
+        fs::remove_dir_all(&other).unwrap();
+    }
+
+    #[test]
+    fn build_cache_keys() {
+        let dir = cache_dir("builds");
+        let builds = Cell::new(0);
+        let build = |args: &EvalArgs, kind: &str, key: &[&[u8]]| {
+            cached_build(args, kind, key, || {
+                builds.set(builds.get() + 1);
+                key.concat()
+            })
+        };
+
+        let args = args(&dir);
+        assert_eq!(build(&args, "asm", &[b"options", b"digest"]), b"optionsdigest");
+        assert_eq!(build(&args, "asm", &[b"options", b"digest"]), b"optionsdigest");
+        assert_eq!(builds.get(), 1);
+        // the kind and every part of the key are in the entry's path
+        build(&args, "elf", &[b"options", b"digest"]);
+        build(&args, "asm", &[b"options", b"other digest"]);
+        // the parts are length-prefixed, so they cannot be shifted into each other
+        build(&args, "asm", &[b"optionsd", b"igest"]);
+        assert_eq!(builds.get(), 4);
+
+        // without a cache, every build runs
+        let args = EvalArgs { setup_cache: None, ..args };
+        build(&args, "asm", &[b"options", b"digest"]);
+        assert_eq!(builds.get(), 5);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn combined_setups() {
+        let secs = Duration::from_secs;
+        let part = |cold, warm: Option<u64>, status| SetupDurations {
+            cold: secs(cold),
+            warm: warm.map(secs),
+            status,
+        };
+        // an uncached part is paid by the warm setup too
+        let setup = part(10, Some(1), CacheStatus::Hit).and(part(5, None, CacheStatus::Off));
+        assert_eq!(
+            (setup.cold, setup.warm, setup.status),
+            (secs(15), Some(secs(6)), CacheStatus::Hit)
+        );
+        let setup = part(10, Some(1), CacheStatus::Hit).and(part(5, Some(2), CacheStatus::Hit));
+        assert_eq!(
+            (setup.cold, setup.warm, setup.status),
+            (secs(15), Some(secs(3)), CacheStatus::Hit)
+        );
+        let setup = part(10, None, CacheStatus::Miss).and(part(5, Some(2), CacheStatus::Hit));
+        assert_eq!(setup.status, CacheStatus::Miss);
+        let setup = part(10, None, CacheStatus::Off).and(part(5, None, CacheStatus::Off));
+        assert_eq!((setup.cold, setup.warm, setup.status), (secs(15), None, CacheStatus::Off));
+    }
+}
@@ -0,0 +1,329 @@
+//! Rendering results as SVG charts, without external tools.
+
+use std::fmt::Write;
+
+const WIDTH: f64 = 720.0;
+const HEIGHT: f64 = 420.0;
+const MARGIN_LEFT: f64 = 80.0;
+const MARGIN_RIGHT: f64 = 170.0;
+const MARGIN_TOP: f64 = 40.0;
+const MARGIN_BOTTOM: f64 = 55.0;
+
+/// The colors of the series, in order.
+const COLORS: &[&str] =
+    &["#1f77b4", "#ff7f0e", "#2ca02c", "#d62728", "#9467bd", "#8c564b", "#e377c2", "#7f7f7f"];
+
+/// How values are mapped onto an axis.
+#[derive(Clone, Copy)]
+pub enum Scale {
+    Linear,
+    /// Base 10, for values spanning orders of magnitude such as cycle counts.
+    Log,
+}
+
+/// A named set of points of a line chart.
+pub struct Series {
+    pub name: String,
+    pub points: Vec<(f64, f64)>,
+}
+
+/// A chart of one line per series, with the points marked.
+pub struct LineChart {
+    pub title: String,
+    pub x_label: String,
+    pub y_label: String,
+    pub x_scale: Scale,
+    /// Whether the points of a series are joined, which only makes sense for a sweep of one
+    /// workload. Otherwise the chart is a scatter plot.
+    pub lines: bool,
+    pub series: Vec<Series>,
+}
+
+/// A chart of grouped bars: a group per category, with a bar per series.
+pub struct BarChart {
+    pub title: String,
+    pub y_label: String,
+    /// The label of each category, on several lines if it contains newlines.
+    pub categories: Vec<String>,
+    /// The name of each series, and its value for each category, if any.
+    pub series: Vec<(String, Vec<Option<f64>>)>,
+}
+
+/// The range of values shown along an axis.
+struct Axis {
+    scale: Scale,
+    min: f64,
+    max: f64,
+}
+
+impl Axis {
+    /// Fits an axis to values, starting at zero on a linear scale if `from_zero` is set.
+    fn fit(scale: Scale, values: impl Iterator<Item = f64>, from_zero: bool) -> Axis {
+        let values: Vec<f64> = values
+            .filter(|v| v.is_finite() && (matches!(scale, Scale::Linear) || *v > 0.0))
+            .collect();
+        let (mut min, mut max) = values
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &v| (min.min(v), max.max(v)));
+        if values.is_empty() {
+            (min, max) = (1.0, 10.0);
+        }
+        match scale {
+            Scale::Linear => {
+                if from_zero {
+                    min = min.min(0.0);
+                }
+                if min == max {
+                    (min, max) = (min - 1.0, max + 1.0);
+                }
+                let step = nice_step((max - min) / 5.0);
+                Axis { scale, min: (min / step).floor() * step, max: (max / step).ceil() * step }
+            }
+            Scale::Log => {
+                let (min, max) = (min.log10().floor(), max.log10().ceil());
+                Axis { scale, min: 10f64.powf(min), max: 10f64.powf(max.max(min + 1.0)) }
+            }
+        }
+    }
+
+    /// Maps a value to its fraction of the axis length.
+    fn fraction(&self, value: f64) -> f64 {
+        match self.scale {
+            Scale::Linear => (value - self.min) / (self.max - self.min),
+            Scale::Log => {
+                (value.log10() - self.min.log10()) / (self.max.log10() - self.min.log10())
+            }
+        }
+    }
+
+    fn ticks(&self) -> Vec<f64> {
+        match self.scale {
+            Scale::Linear => {
+                let step = nice_step((self.max - self.min) / 5.0);
+                let count = ((self.max - self.min) / step).round() as usize;
+                (0..=count).map(|i| self.min + i as f64 * step).collect()
+            }
+            Scale::Log => {
+                let (min, max) = (self.min.log10().round() as i32, self.max.log10().round() as i32);
+                (min..=max).map(|exponent| 10f64.powi(exponent)).collect()
+            }
+        }
+    }
+}
+
+/// Rounds a tick step up to 1, 2 or 5 times a power of ten.
+fn nice_step(raw: f64) -> f64 {
+    let magnitude = 10f64.powf(raw.log10().floor());
+    let step = [1.0, 2.0, 5.0, 10.0].into_iter().find(|m| m * magnitude >= raw).unwrap_or(10.0);
+    step * magnitude
+}
+
+/// Formats a tick label with an SI suffix, e.g. `2.5M`.
+fn format_tick(value: f64) -> String {
+    let (value, suffix) = match value.abs() {
+        v if v >= 1e9 => (value / 1e9, "G"),
+        v if v >= 1e6 => (value / 1e6, "M"),
+        v if v >= 1e3 => (value / 1e3, "k"),
+        _ => (value, ""),
+    };
+    let text = format!("{value:.2}");
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    format!("{text}{suffix}")
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+/// Opens the SVG document and draws the title, the axis labels and the frame of the plot area.
+fn begin(title: &str, x_label: &str, y_label: &str) -> String {
+    let mut svg = String::new();
+    let (plot_width, plot_height) = plot_size();
+    writeln!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{WIDTH}\" height=\"{HEIGHT}\" \
+         viewBox=\"0 0 {WIDTH} {HEIGHT}\" font-family=\"sans-serif\" font-size=\"12\">"
+    )
+    .unwrap();
+    writeln!(svg, "<rect width=\"100%\" height=\"100%\" fill=\"white\"/>").unwrap();
+    writeln!(
+        svg,
+        "<text x=\"{}\" y=\"24\" text-anchor=\"middle\" font-size=\"15\">{}</text>",
+        MARGIN_LEFT + plot_width / 2.0,
+        escape(title)
+    )
+    .unwrap();
+    writeln!(
+        svg,
+        "<text x=\"{}\" y=\"{}\" text-anchor=\"middle\">{}</text>",
+        MARGIN_LEFT + plot_width / 2.0,
+        HEIGHT - 12.0,
+        escape(x_label)
+    )
+    .unwrap();
+    writeln!(
+        svg,
+        "<text transform=\"translate(18 {}) rotate(-90)\" text-anchor=\"middle\">{}</text>",
+        MARGIN_TOP + plot_height / 2.0,
+        escape(y_label)
+    )
+    .unwrap();
+    writeln!(
+        svg,
+        "<rect x=\"{MARGIN_LEFT}\" y=\"{MARGIN_TOP}\" width=\"{plot_width}\" \
+         height=\"{plot_height}\" fill=\"none\" stroke=\"#444\"/>"
+    )
+    .unwrap();
+    svg
+}
+
+fn plot_size() -> (f64, f64) {
+    (WIDTH - MARGIN_LEFT - MARGIN_RIGHT, HEIGHT - MARGIN_TOP - MARGIN_BOTTOM)
+}
+
+/// Draws the y axis ticks and grid lines.
+fn draw_y_axis(svg: &mut String, axis: &Axis) {
+    let (plot_width, plot_height) = plot_size();
+    for tick in axis.ticks() {
+        let y = MARGIN_TOP + plot_height * (1.0 - axis.fraction(tick));
+        writeln!(
+            svg,
+            "<line x1=\"{MARGIN_LEFT}\" y1=\"{y:.1}\" x2=\"{:.1}\" y2=\"{y:.1}\" stroke=\"#ddd\"/>\
+             <text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"end\">{}</text>",
+            MARGIN_LEFT + plot_width,
+            MARGIN_LEFT - 6.0,
+            y + 4.0,
+            format_tick(tick)
+        )
+        .unwrap();
+    }
+}
+
+/// Draws the legend to the right of the plot area.
+fn draw_legend<'a>(svg: &mut String, names: impl Iterator<Item = &'a str>) {
+    let x = WIDTH - MARGIN_RIGHT + 15.0;
+    for (i, name) in names.enumerate() {
+        let y = MARGIN_TOP + 10.0 + i as f64 * 20.0;
+        writeln!(
+            svg,
+            "<rect x=\"{x}\" y=\"{:.1}\" width=\"12\" height=\"12\" fill=\"{}\"/>\
+             <text x=\"{}\" y=\"{:.1}\">{}</text>",
+            y - 10.0,
+            COLORS[i % COLORS.len()],
+            x + 18.0,
+            y,
+            escape(name)
+        )
+        .unwrap();
+    }
+}
+
+impl LineChart {
+    pub fn render(&self) -> String {
+        let (plot_width, plot_height) = plot_size();
+        let points = || self.series.iter().flat_map(|series| series.points.iter());
+        let x_axis = Axis::fit(self.x_scale, points().map(|&(x, _)| x), false);
+        let y_axis = Axis::fit(Scale::Linear, points().map(|&(_, y)| y), true);
+
+        let mut svg = begin(&self.title, &self.x_label, &self.y_label);
+        draw_y_axis(&mut svg, &y_axis);
+        for tick in x_axis.ticks() {
+            let x = MARGIN_LEFT + plot_width * x_axis.fraction(tick);
+            writeln!(
+                svg,
+                "<line x1=\"{x:.1}\" y1=\"{MARGIN_TOP}\" x2=\"{x:.1}\" y2=\"{:.1}\" stroke=\"#ddd\"/>\
+                 <text x=\"{x:.1}\" y=\"{:.1}\" text-anchor=\"middle\">{}</text>",
+                MARGIN_TOP + plot_height,
+                MARGIN_TOP + plot_height + 18.0,
+                format_tick(tick)
+            )
+            .unwrap();
+        }
+
+        for (i, series) in self.series.iter().enumerate() {
+            let color = COLORS[i % COLORS.len()];
+            let mut points: Vec<(f64, f64)> = series
+                .points
+                .iter()
+                .filter(|(x, y)| {
+                    x.is_finite() &&
+                        y.is_finite() &&
+                        (matches!(self.x_scale, Scale::Linear) || *x > 0.0)
+                })
+                .map(|&(x, y)| {
+                    (
+                        MARGIN_LEFT + plot_width * x_axis.fraction(x),
+                        MARGIN_TOP + plot_height * (1.0 - y_axis.fraction(y)),
+                    )
+                })
+                .collect();
+            points.sort_by(|a, b| a.0.total_cmp(&b.0));
+            let path: Vec<String> = points.iter().map(|(x, y)| format!("{x:.1},{y:.1}")).collect();
+            if self.lines && points.len() > 1 {
+                writeln!(
+                    svg,
+                    "<polyline points=\"{}\" fill=\"none\" stroke=\"{color}\" \
+                     stroke-width=\"2\"/>",
+                    path.join(" ")
+                )
+                .unwrap();
+            }
+            for (x, y) in points {
+                writeln!(svg, "<circle cx=\"{x:.1}\" cy=\"{y:.1}\" r=\"3.5\" fill=\"{color}\"/>")
+                    .unwrap();
+            }
+        }
+        draw_legend(&mut svg, self.series.iter().map(|series| series.name.as_str()));
+        svg += "</svg>\n";
+        svg
+    }
+}
+
+impl BarChart {
+    pub fn render(&self) -> String {
+        let (plot_width, plot_height) = plot_size();
+        let values = self.series.iter().flat_map(|(_, values)| values.iter().flatten().copied());
+        let y_axis = Axis::fit(Scale::Linear, values, true);
+
+        let mut svg = begin(&self.title, "", &self.y_label);
+        draw_y_axis(&mut svg, &y_axis);
+        let group_width = plot_width / self.categories.len().max(1) as f64;
+        let bar_width = group_width * 0.8 / self.series.len().max(1) as f64;
+        for (c, category) in self.categories.iter().enumerate() {
+            let group_x = MARGIN_LEFT + c as f64 * group_width;
+            // a line of the label per line of the category
+            for (i, line) in category.lines().enumerate() {
+                writeln!(
+                    svg,
+                    "<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"middle\">{}</text>",
+                    group_x + group_width / 2.0,
+                    MARGIN_TOP + plot_height + 18.0 + i as f64 * 14.0,
+                    escape(line)
+                )
+                .unwrap();
+            }
+            for (s, (name, values)) in self.series.iter().enumerate() {
+                let Some(value) = values.get(c).copied().flatten() else {
+                    continue;
+                };
+                let top = MARGIN_TOP + plot_height * (1.0 - y_axis.fraction(value));
+                let bottom = MARGIN_TOP + plot_height * (1.0 - y_axis.fraction(0.0));
+                writeln!(
+                    svg,
+                    "<rect x=\"{:.1}\" y=\"{top:.1}\" width=\"{bar_width:.1}\" height=\"{:.1}\" \
+                     fill=\"{}\"><title>{} {}: {}</title></rect>",
+                    group_x + group_width * 0.1 + s as f64 * bar_width,
+                    bottom - top,
+                    COLORS[s % COLORS.len()],
+                    escape(&category.replace('\n', " ")),
+                    escape(name),
+                    format_tick(value)
+                )
+                .unwrap();
+            }
+        }
+        draw_legend(&mut svg, self.series.iter().map(|(name, _)| name.as_str()));
+        svg += "</svg>\n";
+        svg
+    }
+}
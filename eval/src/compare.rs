@@ -0,0 +1,253 @@
+//! The `compare` subcommand, detecting regressions between two result sets.
+
+use std::{collections::BTreeMap, path::PathBuf};
+
+use clap::Args;
+
+use crate::results::{read_results, ResultRow, RunKey};
+
+/// The arguments of the `compare` subcommand.
+#[derive(Args, Clone)]
+pub struct CompareArgs {
+    /// The results file of the reference runs.
+    old: PathBuf,
+    /// The results file of the runs to check.
+    new: PathBuf,
+    /// The relative change of a metric that is flagged, e.g. 0.1 for 10%.
+    #[arg(long, default_value_t = 0.1)]
+    threshold: f64,
+    /// With several trials on both sides, a change is only flagged if it is also larger than
+    /// this many standard errors.
+    #[arg(long, default_value_t = 2.0)]
+    sigmas: f64,
+    /// The metrics to compare, all the known ones by default.
+    #[arg(long = "metric")]
+    metrics: Vec<String>,
+}
+
+/// The metrics that are compared, and whether higher values are better.
+const METRICS: &[(&str, bool)] = &[
+    ("cycles", false),
+    ("execution_duration", false),
+    ("setup_duration", false),
+    ("warm_setup_duration", false),
+    ("core_prove_duration", false),
+    ("compress_prove_duration", false),
+    ("prove_duration", false),
+    ("core_verify_duration", false),
+    ("core_proof_size", false),
+    ("compress_proof_size", false),
+    ("peak_memory", false),
+    ("core_speed", true),
+    ("speed", true),
+];
+
+/// The mean and the standard error of the mean of a metric over the trials of a run.
+struct Estimate {
+    mean: f64,
+    /// Only known with two trials or more.
+    standard_error: Option<f64>,
+}
+
+impl Estimate {
+    fn new(rows: &[&ResultRow], metric: &str) -> Option<Estimate> {
+        let values: Vec<f64> = rows.iter().filter_map(|row| row.metric(metric)).collect();
+        if values.is_empty() {
+            return None;
+        }
+        let n = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / n;
+        let standard_error = (values.len() > 1).then(|| {
+            let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0);
+            (variance / n).sqrt()
+        });
+        Some(Estimate { mean, standard_error })
+    }
+}
+
+/// Groups the successful trials by run, and lists the runs where every trial failed.
+fn group(rows: &[ResultRow]) -> (BTreeMap<RunKey, Vec<&ResultRow>>, Vec<RunKey>) {
+    let mut runs: BTreeMap<RunKey, Vec<&ResultRow>> = BTreeMap::new();
+    for row in rows {
+        runs.entry(row.key()).or_default().push(row);
+    }
+    let failed = runs
+        .iter()
+        .filter(|(_, trials)| trials.iter().all(|row| row.failed()))
+        .map(|(key, _)| key.clone())
+        .collect();
+    for trials in runs.values_mut() {
+        trials.retain(|row| !row.failed());
+    }
+    (runs, failed)
+}
+
+pub fn run(args: &CompareArgs) {
+    let (old_rows, new_rows) = (read_results(&args.old), read_results(&args.new));
+    let metrics: Vec<(&str, bool)> = if args.metrics.is_empty() {
+        METRICS.to_vec()
+    } else {
+        args.metrics
+            .iter()
+            .map(|name| {
+                *METRICS
+                    .iter()
+                    .find(|(metric, _)| metric == name)
+                    .unwrap_or_else(|| panic!("Unknown metric {}", name))
+            })
+            .collect()
+    };
+
+    let (lines, regressions) = compare(&old_rows, &new_rows, &metrics, args);
+    for line in lines {
+        println!("{line}");
+    }
+    if regressions > 0 {
+        println!("{regressions} regressions found");
+        std::process::exit(1);
+    }
+    println!("no regressions found");
+}
+
+/// Compares the runs of two result sets, returning the report lines and the number of
+/// regressions among them.
+fn compare(
+    old_rows: &[ResultRow],
+    new_rows: &[ResultRow],
+    metrics: &[(&str, bool)],
+    args: &CompareArgs,
+) -> (Vec<String>, usize) {
+    let (old, _) = group(old_rows);
+    let (new, new_failed) = group(new_rows);
+    let mut lines = vec![];
+    let mut regressions = 0;
+    for (key, old_trials) in &old {
+        if old_trials.is_empty() {
+            continue;
+        }
+        let Some(new_trials) = new.get(key) else {
+            lines.push(format!("{key}: missing in {}", args.new.display()));
+            regressions += 1;
+            continue;
+        };
+        if new_failed.contains(key) {
+            lines.push(format!("{key}: failed in {}", args.new.display()));
+            regressions += 1;
+            continue;
+        }
+        for &(metric, higher_is_better) in metrics {
+            let (Some(before), Some(after)) =
+                (Estimate::new(old_trials, metric), Estimate::new(new_trials, metric))
+            else {
+                continue;
+            };
+            if before.mean == 0.0 {
+                continue;
+            }
+            let change = (after.mean - before.mean) / before.mean;
+            if change.abs() <= args.threshold {
+                continue;
+            }
+            // with trials on both sides, ignore changes within the noise
+            if let (Some(se_before), Some(se_after)) = (before.standard_error, after.standard_error)
+            {
+                let standard_error = (se_before.powi(2) + se_after.powi(2)).sqrt();
+                if (after.mean - before.mean).abs() <= args.sigmas * standard_error {
+                    continue;
+                }
+            }
+            let regression = (change > 0.0) != higher_is_better;
+            lines.push(format!(
+                "{key}: {metric} {} -> {} ({:+.1}%){}",
+                before.mean,
+                after.mean,
+                change * 100.0,
+                if regression { " REGRESSION" } else { "" }
+            ));
+            if regression {
+                regressions += 1;
+            }
+        }
+    }
+    for key in new.keys().filter(|key| !old.contains_key(*key)) {
+        lines.push(format!("{key}: new in {}", args.new.display()));
+    }
+    (lines, regressions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::results::{
+        testing::{ended, row},
+        RunStatus,
+    };
+
+    fn args() -> CompareArgs {
+        CompareArgs {
+            old: PathBuf::from("old.csv"),
+            new: PathBuf::from("new.csv"),
+            threshold: 0.1,
+            sigmas: 2.0,
+            metrics: vec![],
+        }
+    }
+
+    fn prove(durations: &[f64]) -> Vec<ResultRow> {
+        durations.iter().map(|&d| row("sp1", 20, &[("core_prove_duration", d)])).collect()
+    }
+
+    fn regressions(old: &[ResultRow], new: &[ResultRow]) -> usize {
+        compare(old, new, METRICS, &args()).1
+    }
+
+    /// The number of reported lines and of regressions among them.
+    fn regressions_of(old: &[ResultRow], new: &[ResultRow]) -> (usize, usize) {
+        let (lines, regressions) = compare(old, new, METRICS, &args());
+        (lines.len(), regressions)
+    }
+
+    #[test]
+    fn changes_within_the_threshold_are_ignored() {
+        let (lines, regressions) = compare(&prove(&[10.0]), &prove(&[10.9]), METRICS, &args());
+        assert!(lines.is_empty(), "{lines:?}");
+        assert_eq!(regressions, 0);
+    }
+
+    #[test]
+    fn slower_proving_is_a_regression() {
+        let (lines, regressions) = compare(&prove(&[10.0]), &prove(&[12.0]), METRICS, &args());
+        assert_eq!(regressions, 1);
+        assert_eq!(
+            lines,
+            ["fibonacci sp1 poseidon 20: core_prove_duration 10 -> 12 (+20.0%) REGRESSION"]
+        );
+        // faster proving is reported, but is not a regression
+        assert_eq!(regressions_of(&prove(&[12.0]), &prove(&[10.0])), (1, 0));
+    }
+
+    #[test]
+    fn higher_throughput_is_not_a_regression() {
+        let speed = |s: f64| vec![row("sp1", 20, &[("core_prove_duration", 1.0), ("speed", s)])];
+        assert_eq!(regressions(&speed(100.0), &speed(150.0)), 0);
+        assert_eq!(regressions(&speed(150.0), &speed(100.0)), 1);
+    }
+
+    #[test]
+    fn noisy_changes_are_ignored() {
+        // a 20% change of the means, within two standard errors
+        assert_eq!(regressions(&prove(&[8.0, 12.0]), &prove(&[9.0, 15.0])), 0);
+        // the same change with little noise
+        assert_eq!(regressions(&prove(&[9.9, 10.1]), &prove(&[11.9, 12.1])), 1);
+    }
+
+    #[test]
+    fn failed_and_missing_runs_are_regressions() {
+        let failed = [ended("sp1", 20, RunStatus::Failed)];
+        assert_eq!(regressions(&prove(&[10.0]), &failed), 1);
+        assert_eq!(regressions(&prove(&[10.0]), &[]), 1);
+        // a run that failed before does not count, nor does a new one
+        assert_eq!(regressions(&failed, &[]), 0);
+        assert_eq!(regressions_of(&[], &prove(&[10.0])), (1, 0));
+    }
+}
@@ -0,0 +1,179 @@
+//! The configuration of each prover, derived from the CLI arguments.
+//!
+//! `--shard-size n` sets a different parameter, in a different unit, for each prover. The
+//! configurations spell out the resulting parameters with their units, and are recorded with each
+//! result so that runs of different provers are not compared on the shard size alone.
+
+use std::{collections::BTreeMap, env, fs};
+
+use serde::Serialize;
+
+use crate::{EvalArgs, PowdrDegreeMode, PowdrLinker, ProgramId};
+
+/// Parses a `key=value` prover option.
+pub fn parse_prover_opt(opt: &str) -> Result<(String, String), String> {
+    let (key, value) =
+        opt.split_once('=').ok_or_else(|| format!("expected key=value, got {opt}"))?;
+    Ok((key.trim().to_string(), value.trim().to_string()))
+}
+
+/// Collects the prover options of `--prover-opts-file`, then of `--prover-opt`, so that the
+/// latter override the former.
+pub fn prover_opts(args: &EvalArgs) -> Vec<(String, String)> {
+    let mut opts = vec![];
+    if let Some(path) = &args.prover_opts_file {
+        let content = fs::read_to_string(path)
+            .unwrap_or_else(|_| panic!("Failed to read prover options file: {:?}", path));
+        for line in content.lines().map(|line| line.trim()) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            opts.push(parse_prover_opt(line).unwrap_or_else(|err| panic!("{:?}: {}", path, err)));
+        }
+    }
+    opts.extend(args.prover_opts.iter().cloned());
+    opts
+}
+
+/// Parses the value of a prover option.
+pub fn parse_opt_value<T: std::str::FromStr>(key: &str, value: &str) -> T {
+    value.parse().unwrap_or_else(|_| panic!("Invalid value for prover option {key}: {value}"))
+}
+
+/// The configuration of SP1, passed to the prover through environment variables.
+#[derive(Debug, Clone, Serialize)]
+pub struct Sp1Config {
+    /// The number of cycles per shard, `2^n` for `--shard-size n` (`SHARD_SIZE`).
+    pub shard_size_cycles: u64,
+    /// The number of shards the executor runs between checkpoints, as a multiple of the shard
+    /// batch size (`SHARD_CHUNKING_MULTIPLIER`), set with the `shard_chunking_multiplier` prover
+    /// option or the environment variable. Otherwise SP1 defaults to 1; the reth program uses 4.
+    pub shard_chunking_multiplier: u64,
+    /// The effective `SP1ProverOpts`, with the prover options applied.
+    pub prover_opts: BTreeMap<String, String>,
+}
+
+impl Sp1Config {
+    pub fn new(args: &EvalArgs) -> Sp1Config {
+        Sp1Config {
+            shard_size_cycles: 1 << args.shard_size,
+            shard_chunking_multiplier: shard_chunking_multiplier(
+                &prover_opts(args),
+                env::var("SHARD_CHUNKING_MULTIPLIER").ok().as_deref(),
+                &args.program,
+            ),
+            prover_opts: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the environment variables SP1 reads its options from. Both are always set, so that
+    /// the prover sees the recorded values.
+    pub fn apply(&self) {
+        env::set_var("SHARD_SIZE", self.shard_size_cycles.to_string());
+        env::set_var("SHARD_CHUNKING_MULTIPLIER", self.shard_chunking_multiplier.to_string());
+    }
+}
+
+/// The shard chunking multiplier of SP1, from the last `shard_chunking_multiplier` prover option,
+/// else from `SHARD_CHUNKING_MULTIPLIER` in the environment, else the default of the program.
+fn shard_chunking_multiplier(
+    opts: &[(String, String)],
+    env_value: Option<&str>,
+    program: &ProgramId,
+) -> u64 {
+    let opt = opts.iter().rev().find(|(key, _)| key == "shard_chunking_multiplier");
+    match (opt, env_value) {
+        (Some((key, value)), _) => parse_opt_value(key, value),
+        (None, Some(value)) => parse_opt_value("SHARD_CHUNKING_MULTIPLIER", value),
+        (None, None) if *program == ProgramId::Reth => 4,
+        (None, None) => 1,
+    }
+}
+
+/// The configuration of RISC0.
+#[derive(Debug, Clone, Serialize)]
+pub struct Risc0Config {
+    /// The log2 of the maximum number of cycles per segment, `n` for `--shard-size n`. Unlike
+    /// SP1's shard size, the segment cycles include paging and padding, not only user cycles.
+    pub segment_limit_po2: u32,
+    /// The effective `ProverOpts`, with the prover options applied.
+    pub prover_opts: BTreeMap<String, String>,
+}
+
+impl Risc0Config {
+    pub fn new(args: &EvalArgs) -> Risc0Config {
+        Risc0Config { segment_limit_po2: args.shard_size as u32, prover_opts: BTreeMap::new() }
+    }
+}
+
+/// The configuration of powdr.
+#[derive(Debug, Clone, Serialize)]
+pub struct PowdrConfig {
+    /// The log2 of the maximum number of rows of each machine, `n` for `--shard-size n`. Rows are
+    /// not RISC-V cycles: an instruction may take several rows, in several machines.
+    pub max_degree_log: u8,
+    /// Whether the execution is split into chunks proven separately. The brainfuck asm programs
+    /// are not RISC-V programs, so they always run without continuations.
+    pub continuations: bool,
+    /// The linker connecting the machines (`--powdr-linker`).
+    pub linker: PowdrLinker,
+    /// How the machines are sized (`--powdr-degree-mode`): each on its own with VADCOP, or all
+    /// with the maximum degree when monolithic.
+    pub degree_mode: PowdrDegreeMode,
+}
+
+impl PowdrConfig {
+    pub fn new(args: &EvalArgs) -> PowdrConfig {
+        let riscv = !matches!(
+            args.program,
+            ProgramId::BrainfuckAsm |
+                ProgramId::BrainfuckCompiler |
+                ProgramId::BrainfuckCompilerOpt
+        );
+        PowdrConfig {
+            max_degree_log: args.shard_size as u8,
+            continuations: riscv && !args.powdr_no_continuations,
+            linker: args.powdr_linker,
+            degree_mode: args.powdr_degree_mode,
+        }
+    }
+}
+
+/// The configuration of the prover of a run, as recorded in the results.
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum ProverConfig {
+    Sp1(Sp1Config),
+    Risc0(Risc0Config),
+    Powdr(PowdrConfig),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn opts(opts: &[&str]) -> Vec<(String, String)> {
+        opts.iter().map(|opt| parse_prover_opt(opt).unwrap()).collect()
+    }
+
+    #[test]
+    fn shard_chunking_multiplier_defaults_per_program() {
+        assert_eq!(shard_chunking_multiplier(&[], None, &ProgramId::Fibonacci), 1);
+        assert_eq!(shard_chunking_multiplier(&[], None, &ProgramId::Reth), 4);
+    }
+
+    #[test]
+    fn shard_chunking_multiplier_from_the_user() {
+        // the environment is not overwritten with the default
+        assert_eq!(shard_chunking_multiplier(&[], Some("2"), &ProgramId::Reth), 2);
+        // the prover option wins over the environment, and the last one over earlier ones
+        let opts = opts(&["shard_chunking_multiplier=8", "shard_chunking_multiplier = 16"]);
+        assert_eq!(shard_chunking_multiplier(&opts, Some("2"), &ProgramId::Fibonacci), 16);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid value for prover option SHARD_CHUNKING_MULTIPLIER")]
+    fn invalid_shard_chunking_multiplier() {
+        shard_chunking_multiplier(&[], Some("four"), &ProgramId::Reth);
+    }
+}
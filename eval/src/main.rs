@@ -1,30 +1,71 @@
 // #[cfg(feature = "jolt-zkvm")]
 // mod jolt;
 
+mod blocks;
+mod brainfuck;
+mod cache;
+mod chart;
+mod compare;
+mod config;
 #[cfg(any(feature = "powdr-estark", feature = "powdr-plonky3"))]
 mod powdr;
+mod proofs;
+mod report;
+mod results;
 #[cfg(feature = "risc0")]
 mod risc0;
 #[cfg(feature = "sp1")]
 mod sp1;
+mod stats;
+mod tune;
 mod types;
 mod utils;
 
 use std::{
+    collections::BTreeMap,
     fs::{create_dir_all, OpenOptions},
+    panic::{self, AssertUnwindSafe},
     path::PathBuf,
 };
 
-use clap::{command, Parser};
+use clap::{command, Args, Parser, Subcommand};
 use csv::WriterBuilder;
+use results::RunStatus;
 use serde::Serialize;
 use types::*;
 
 use cfg_if::cfg_if;
 
+/// The command line interface. Without a subcommand, a single benchmark is run.
+#[derive(Parser)]
+#[command(
+    about = "Evaluate the performance of a zkVM on a program.",
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+    #[command(flatten)]
+    eval: Option<EvalArgs>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Prepare, list and inspect the Reth block inputs.
+    Blocks(blocks::BlocksArgs),
+    /// Generate Markdown and HTML tables comparing the provers from results files.
+    Report(report::ReportArgs),
+    /// Compare two results files and exit with an error on regressions.
+    Compare(compare::CompareArgs),
+    /// Search the shard size with the fastest core proving, optionally under a memory limit.
+    Tune(tune::TuneArgs),
+    /// Verify the proofs saved with `--save-proofs` in a fresh process.
+    Verify(proofs::VerifyArgs),
+}
+
 /// The argument passed through the CLI.
-#[derive(Parser, Clone)]
-#[command(about = "Evaluate the performance of a zkVM on a program.")]
+#[derive(Args, Clone)]
 pub struct EvalArgs {
     #[arg(long)]
     program: ProgramId,
@@ -32,15 +73,60 @@ pub struct EvalArgs {
     prover: ProverId,
     #[arg(long)]
     hashfn: HashFnId,
+    /// The shard size, whose meaning depends on the prover: `2^n` cycles per shard for SP1, the
+    /// log2 of the segment cycles for RISC0 and the log2 of the rows per machine for powdr.
     #[arg(long)]
     shard_size: u64,
     #[arg(long)]
     filename: String,
     #[arg(long)]
     powdr_no_continuations: bool,
+    /// The linker powdr connects the machines of the program with.
+    #[arg(long, default_value = "native")]
+    powdr_linker: PowdrLinker,
+    /// Whether powdr sizes each machine on its own (VADCOP) or gives every machine the maximum
+    /// degree.
+    #[arg(long, default_value = "vadcop")]
+    powdr_degree_mode: PowdrDegreeMode,
+    /// Only execute the program, reporting its cycles and shards without proving it.
+    #[arg(long, conflicts_with = "core_only")]
+    execute_only: bool,
+    /// Only generate the core proof, skipping its compression.
+    #[arg(long)]
+    core_only: bool,
+    /// A prover option as `key=value`, such as `core.shard_batch_size=8` for SP1 or
+    /// `receipt_kind=groth16` for RISC0. Can be repeated, and overrides `--prover-opts-file`.
+    #[arg(long = "prover-opt", value_parser = config::parse_prover_opt)]
+    prover_opts: Vec<(String, String)>,
+    /// A file of prover options, one `key=value` per line.
+    #[arg(long)]
+    prover_opts_file: Option<PathBuf>,
+    /// Save the proofs, verifying key and public values to this directory, to be verified later
+    /// with the `verify` subcommand.
+    #[arg(long)]
+    save_proofs: Option<PathBuf>,
+    /// Cache the prover setups, such as SP1's proving key and powdr's fixed columns, in this
+    /// directory, keyed by the program, shard size and backend version, along with the compiled
+    /// powdr asm.
+    #[arg(long)]
+    setup_cache: Option<PathBuf>,
+    /// Run a program of the brainfuck corpus, checking its output, instead of the program and
+    /// input given as paths.
+    #[arg(long, conflicts_with = "program_inputs")]
+    bf_case: Option<BrainfuckCase>,
     program_inputs: Vec<String>,
 }
 
+impl EvalArgs {
+    /// Describes the program inputs in the results.
+    fn program_args(&self) -> String {
+        match self.bf_case {
+            Some(case) => case.to_string(),
+            None => self.program_inputs.join(" "),
+        }
+    }
+}
+
 /// The performance report of a zkVM on a program.
 #[derive(Debug, Serialize, Default)]
 pub struct PerformanceReport {
@@ -50,13 +136,13 @@ pub struct PerformanceReport {
     ///
     /// Note that this number may vary based on the zkVM.
     pub cycles: u64,
-    /// The reported speed in cycles per second for the core proof.
+    /// The core throughput, in cycles per second of core proving.
     pub core_speed: f64,
-    /// The reported speed in cycles per second.
+    /// The end-to-end throughput, in cycles per second of proving including compression.
     pub speed: f64,
     /// The reported duration of the execution in seconds.
     pub execution_duration: f64,
-    /// The reported duration of the prover in seconds.
+    /// The duration of the prover in seconds, core proving and compression.
     pub prove_duration: f64,
     /// The reported duration of the core proving time in seconds.
     pub core_prove_duration: f64,
@@ -70,19 +156,101 @@ pub struct PerformanceReport {
     pub compress_verify_duration: f64,
     /// The size of the recursive proof in bytes.
     pub compress_proof_size: usize,
-    /// Time to setup the prover/verifier.
+    /// Time to setup the prover/verifier, without the setup cache.
     pub setup_duration: f64,
+    /// Time to setup the prover/verifier when the setup cache has an entry for the run.
+    pub warm_setup_duration: Option<f64>,
+    /// Whether the setup was read from the setup cache.
+    pub setup_cache: cache::CacheStatus,
+    /// The peak resident memory of the run in bytes, 0 where it cannot be read.
+    pub peak_memory: u64,
+    /// The gas used by the block, for the reth program.
+    pub gas_used: u64,
+    /// The number of transactions in the block, for the reth program.
+    pub transactions: usize,
+    /// The number of cycles per unit of gas, for the reth program.
+    pub cycles_per_gas: f64,
+    /// The core proving time in seconds per million gas, for the reth program.
+    pub core_prove_duration_per_mgas: f64,
+    /// The proving time including compression in seconds per million gas, for the reth program.
+    pub prove_duration_per_mgas: f64,
+    /// The cycles spent in each cycle region of the guest, if it has any, or `None` if the prover
+    /// cannot track them, as with powdr.
+    pub cycle_regions: Option<BTreeMap<String, u64>>,
+    /// The number of times each opcode and syscall was executed, as kind, name and count, if the
+    /// prover reports them.
+    pub histograms: Vec<(String, String, u64)>,
+    /// The duration in seconds of each phase of the run, in order, if the prover reports them.
+    pub phases: Vec<(String, f64)>,
+    /// The configuration of the prover.
+    pub prover_config: Option<config::ProverConfig>,
+    /// The statistics specific to the prover, if it reports any.
+    pub prover_stats: Option<stats::ProverStats>,
+    /// How the run ended.
+    pub status: RunStatus,
+}
+
+impl PerformanceReport {
+    /// Fills in the proving duration and the throughputs from the measured core proving and
+    /// compression, so that they are computed the same way for every prover. They are left at 0
+    /// when nothing was proven.
+    fn add_speed_metrics(&mut self) {
+        self.prove_duration = self.core_prove_duration + self.compress_prove_duration;
+        if self.core_prove_duration > 0.0 {
+            self.core_speed = self.cycles as f64 / self.core_prove_duration;
+            self.speed = self.cycles as f64 / self.prove_duration;
+        }
+    }
+
+    /// Fills in the gas-normalised metrics of a reth run. They are left at 0 for a block that uses
+    /// no gas.
+    fn add_gas_metrics(&mut self, gas_used: u64, transactions: usize) {
+        self.gas_used = gas_used;
+        self.transactions = transactions;
+        if gas_used == 0 {
+            return;
+        }
+        let mgas = gas_used as f64 / 1_000_000.0;
+        self.cycles_per_gas = self.cycles as f64 / gas_used as f64;
+        self.core_prove_duration_per_mgas = self.core_prove_duration / mgas;
+        self.prove_duration_per_mgas = self.prove_duration / mgas;
+    }
 }
 
 fn main() {
-    let args = EvalArgs::parse();
+    let cli = Cli::parse();
+    match cli.command {
+        Some(Command::Blocks(args)) => blocks::run(&args),
+        Some(Command::Report(args)) => report::run(&args),
+        Some(Command::Compare(args)) => compare::run(&args),
+        Some(Command::Tune(args)) => tune::run(&args),
+        Some(Command::Verify(args)) => proofs::run(&args),
+        None => run_benchmark(&cli.eval.expect("benchmark arguments are required")),
+    }
+}
+
+/// The results file of the benchmarks run with `--filename`, for the current commit.
+fn results_path(filename: &str) -> PathBuf {
+    PathBuf::from("benchmarks").join(format!("{}_{}.csv", filename, env!("VERGEN_GIT_SHA")))
+}
 
-    // Select the correct implementation based on the prover.
-    let report: PerformanceReport = match args.prover {
+/// Runs a single benchmark and appends the report to the results file.
+fn run_benchmark(args: &EvalArgs) {
+    // Record unsupported configurations explicitly, rather than running a different one.
+    if !args.prover.supports_hashfn(&args.hashfn) {
+        println!("{} does not support the {} hash function", args.prover, args.hashfn);
+        let report = PerformanceReport { status: RunStatus::Unsupported, ..Default::default() };
+        write_results(args, &report);
+        return;
+    }
+
+    // Select the correct implementation based on the prover. A prover that panics is recorded as a
+    // failed run, and then fails the benchmark as before.
+    let report = panic::catch_unwind(AssertUnwindSafe(|| match args.prover {
         ProverId::Risc0 => {
             cfg_if! {
                 if #[cfg(feature = "risc0")] {
-                    risc0::Risc0Evaluator::eval(&args)
+                    risc0::Risc0Evaluator::eval(args)
                 } else {
                     unreachable!()
                 }
@@ -91,7 +259,7 @@ fn main() {
         ProverId::SP1 => {
             cfg_if! {
                 if #[cfg(feature = "sp1")] {
-                    sp1::SP1Evaluator::eval(&args)
+                    sp1::SP1Evaluator::eval(args)
                 } else {
                     unreachable!()
                 }
@@ -109,7 +277,7 @@ fn main() {
         ProverId::PowdrEstark => {
             cfg_if! {
                 if #[cfg(feature = "powdr-estark")] {
-                    powdr::PowdrEvaluator::eval(&args)
+                    powdr::PowdrEvaluator::eval(args)
                 } else {
                     unreachable!()
                 }
@@ -118,21 +286,46 @@ fn main() {
         ProverId::PowdrPlonky3 => {
             cfg_if! {
                 if #[cfg(feature = "powdr-plonky3")] {
-                    powdr::PowdrEvaluator::eval(&args)
+                    powdr::PowdrEvaluator::eval(args)
                 } else {
                     unreachable!()
                 }
             }
         }
-    };
+    }));
+    let mut report = report.unwrap_or_else(|panic| {
+        let report = PerformanceReport {
+            status: RunStatus::Failed,
+            peak_memory: utils::peak_memory(std::process::id()).unwrap_or(0),
+            ..Default::default()
+        };
+        write_results(args, &report);
+        panic::resume_unwind(panic)
+    });
+    if args.execute_only {
+        report.status = RunStatus::Executed;
+    }
+
+    report.add_speed_metrics();
 
+    // Normalise the reth workload by the gas used in the block.
+    if args.program == ProgramId::Reth {
+        let stats = blocks::block_stats(utils::get_reth_block_number(args));
+        report.add_gas_metrics(stats.gas_used, stats.transactions);
+    }
+
+    report.peak_memory = utils::peak_memory(std::process::id()).unwrap_or(0);
+    write_results(args, &report);
+}
+
+/// Appends the report of a benchmark to the results file.
+fn write_results(args: &EvalArgs, report: &PerformanceReport) {
     // Create the results directory if it doesn't exist.
     let results_dir = PathBuf::from("benchmarks");
     create_dir_all(&results_dir).unwrap();
 
     // Create the file.
-    let filename = format!("{}_{}.csv", args.filename, env!("VERGEN_GIT_SHA"));
-    let path = results_dir.join(filename);
+    let path = results_path(&args.filename);
     let file = OpenOptions::new().create(true).append(true).open(path.clone()).unwrap();
 
     // Write the row and the header, if needed.
@@ -147,6 +340,7 @@ fn main() {
                 "shard_size",
                 "shards",
                 "cycles",
+                "core_speed",
                 "speed",
                 "execution_duration",
                 "prove_duration",
@@ -157,32 +351,102 @@ fn main() {
                 "compress_verify_duration",
                 "compress_proof_size",
                 "setup_duration",
+                "warm_setup_duration",
+                "setup_cache",
+                "peak_memory",
+                "gas_used",
+                "transactions",
+                "cycles_per_gas",
+                "core_prove_duration_per_mgas",
+                "prove_duration_per_mgas",
+                "prover_config",
+                "prover_stats",
+                "cycle_regions",
+                "phases",
+                "status",
             ])
             .unwrap();
     }
+    // the metrics a run did not get to are left empty rather than 0
+    let proven = report.status == RunStatus::Ok;
+    let executed = proven || report.status == RunStatus::Executed;
+    let proving = |value: String| if proven { value } else { String::new() };
+    let execution = |value: String| if executed { value } else { String::new() };
     writer
         .serialize(&[
             args.program.to_string(),
-            args.program_inputs.join(" "),
+            args.program_args(),
             args.prover.to_string(),
             args.hashfn.to_string(),
             args.shard_size.to_string(),
-            report.shards.to_string(),
-            report.cycles.to_string(),
-            report.speed.to_string(),
-            report.execution_duration.to_string(),
-            report.prove_duration.to_string(),
-            report.core_prove_duration.to_string(),
-            report.core_verify_duration.to_string(),
-            report.core_proof_size.to_string(),
-            report.compress_prove_duration.to_string(),
-            report.compress_verify_duration.to_string(),
-            report.compress_proof_size.to_string(),
-            report.setup_duration.to_string(),
+            execution(report.shards.to_string()),
+            execution(report.cycles.to_string()),
+            proving(report.core_speed.to_string()),
+            proving(report.speed.to_string()),
+            execution(report.execution_duration.to_string()),
+            proving(report.prove_duration.to_string()),
+            proving(report.core_prove_duration.to_string()),
+            proving(report.core_verify_duration.to_string()),
+            proving(report.core_proof_size.to_string()),
+            proving(report.compress_prove_duration.to_string()),
+            proving(report.compress_verify_duration.to_string()),
+            proving(report.compress_proof_size.to_string()),
+            proving(report.setup_duration.to_string()),
+            report.warm_setup_duration.map(|d| d.to_string()).unwrap_or_default(),
+            report.setup_cache.to_string(),
+            report.peak_memory.to_string(),
+            execution(report.gas_used.to_string()),
+            execution(report.transactions.to_string()),
+            execution(report.cycles_per_gas.to_string()),
+            proving(report.core_prove_duration_per_mgas.to_string()),
+            proving(report.prove_duration_per_mgas.to_string()),
+            serde_json::to_string(&report.prover_config).unwrap(),
+            serde_json::to_string(&report.prover_stats).unwrap(),
+            execution(match &report.cycle_regions {
+                Some(regions) => serde_json::to_string(regions).unwrap(),
+                None => "unsupported".to_string(),
+            }),
+            serde_json::to_string(&report.phases).unwrap(),
+            report.status.to_string(),
         ])
         .unwrap();
     writer.flush().unwrap();
 
+    // Write the opcode and syscall histograms, if the prover reported them.
+    if !report.histograms.is_empty() {
+        let filename = format!("{}_{}_histograms.csv", args.filename, env!("VERGEN_GIT_SHA"));
+        let file =
+            OpenOptions::new().create(true).append(true).open(results_dir.join(filename)).unwrap();
+        let mut writer = WriterBuilder::new().from_writer(&file);
+        if file.metadata().unwrap().len() == 0 {
+            writer
+                .write_record([
+                    "program",
+                    "program_args",
+                    "prover",
+                    "shard_size",
+                    "kind",
+                    "name",
+                    "count",
+                ])
+                .unwrap();
+        }
+        for (kind, name, count) in &report.histograms {
+            writer
+                .write_record([
+                    args.program.to_string(),
+                    args.program_args(),
+                    args.prover.to_string(),
+                    args.shard_size.to_string(),
+                    kind.clone(),
+                    name.clone(),
+                    count.to_string(),
+                ])
+                .unwrap();
+        }
+        writer.flush().unwrap();
+    }
+
     let latest_filename = "benchmarks_latest.csv";
     let latest_path = results_dir.join(latest_filename);
     std::fs::copy(&path, &latest_path).unwrap();
@@ -1,27 +1,247 @@
 use std::{
-    path::PathBuf,
+    cell::RefCell,
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
     time::{Duration, Instant},
 };
 
 use powdr_number::{FieldElement, GoldilocksField, KnownField};
 use powdr_pipeline::{
     pipeline::{DegreeMode, LinkerMode, LinkerParams},
-    Pipeline,
+    BackendType, Pipeline,
+};
+use powdr_riscv::continuations::{
+    bootloader::{BOOTLOADER_INPUTS_PER_PAGE, PAGE_INPUTS_OFFSET, PC_INDEX},
+    DryRunResult,
 };
 
 use cfg_if::cfg_if;
 
-use crate::{utils::*, EvalArgs, HashFnId, PerformanceReport, ProgramId, ProverId};
+use crate::{
+    brainfuck::*,
+    cache::{cached_build, cached_setup, crate_digest, CacheStatus, SetupDurations},
+    config::{prover_opts, PowdrConfig, ProverConfig},
+    proofs::{read_proof_file, save_proofs, Manifest},
+    stats::{PowdrChunkStats, PowdrMachineStats, PowdrStats, ProverStats},
+    utils::*,
+    EvalArgs, PerformanceReport, PowdrDegreeMode, PowdrLinker, ProgramId, ProverId,
+};
 
 // TODO: build to some other directory?
 const OUTPUT_DIR: &str = "/tmp";
 
+/// The tag of the powdr dependencies, which the setup cache and the compiled asm are keyed by.
+const POWDR_VERSION: &str = "v0.1.3";
+
 pub struct PowdrEvaluator;
 
-fn run<T: FieldElement>(mut pipeline: powdr_pipeline::Pipeline<T>) -> PerformanceReport {
+/// Collects the bytes the program writes to stdout through `Output(1, byte)` queries, such as the
+/// output of the brainfuck programs, and echoes them. It must be given a pipeline without query
+/// callbacks, so that it is the first of the chain and answers these queries before the default
+/// handler that `with_prover_inputs` adds.
+fn capture_output<T: FieldElement>(pipeline: Pipeline<T>) -> (Pipeline<T>, Arc<Mutex<Vec<u8>>>) {
+    let output = Arc::new(Mutex::new(vec![]));
+    let captured = output.clone();
+    let pipeline = pipeline.add_query_callback(Arc::new(move |query: &str| {
+        let Some(args) = query.strip_prefix("Output(").and_then(|q| q.strip_suffix(')')) else {
+            return Ok(None);
+        };
+        match args.split_once(',').map(|(fd, byte)| (fd.trim(), byte.trim())) {
+            Some(("1", byte)) => {
+                let byte: u8 = byte.parse().map_err(|e| format!("invalid output byte: {e}"))?;
+                captured.lock().unwrap().push(byte);
+                print!("{}", byte as char);
+                Ok(Some(T::from(0)))
+            }
+            _ => Ok(None),
+        }
+    }));
+    (pipeline, output)
+}
+
+/// Collects the statistics of the chunks of a dry run. The pages of a chunk are the bootloader
+/// inputs after the registers and merkle roots, and its rows are those before the shutdown routine.
+fn chunk_stats<T: FieldElement>(dry_run: &DryRunResult<T>) -> PowdrStats {
+    let chunks = dry_run
+        .bootloader_inputs
+        .iter()
+        .map(|(inputs, start_of_shutdown_routine)| PowdrChunkStats {
+            rows: *start_of_shutdown_routine,
+            pages: (inputs.len() - PAGE_INPUTS_OFFSET) / BOOTLOADER_INPUTS_PER_PAGE,
+            bootloader_rows: None,
+        })
+        .collect();
+    PowdrStats { chunks, ..Default::default() }
+}
+
+/// The rows the bootloader of a chunk uses, read from its witness: the rows before the program
+/// first reaches `start_pc`, the pc of the chunk's bootloader inputs, which the bootloader jumps to
+/// once the pages are loaded.
+fn bootloader_rows<T: FieldElement>(pipeline: &Pipeline<T>, start_pc: T) -> Option<u64> {
+    let witness = pipeline.witness().unwrap();
+    let (_, pc) = witness.iter().find(|(name, _)| name == "main::pc")?;
+    pc.iter().position(|&pc| pc == start_pc).map(|row| row as u64)
+}
+
+/// Records the rows the bootloader of each chunk used, and the share of the rows of all chunks they
+/// make up if they are known for every chunk.
+fn add_bootloader_stats(stats: &mut PowdrStats, bootloader_rows: Vec<Option<u64>>) {
+    for (chunk, rows) in stats.chunks.iter_mut().zip(bootloader_rows) {
+        chunk.bootloader_rows = rows;
+    }
+    let rows: u64 = stats.chunks.iter().map(|chunk| chunk.rows).sum();
+    stats.bootloader_share = stats
+        .chunks
+        .iter()
+        .map(|chunk| chunk.bootloader_rows)
+        .sum::<Option<u64>>()
+        .map(|bootloader_rows| bootloader_rows as f64 / rows as f64);
+}
+
+/// The machine a column belongs to, the namespace of its name.
+fn machine_of(column: &str) -> &str {
+    column.rsplit_once("::").map_or("", |(namespace, _)| namespace)
+}
+
+/// Records the fixed columns and identities of the optimized PIL, and the fixed columns of each
+/// machine.
+fn add_pil_stats<T: FieldElement>(stats: &mut PowdrStats, pipeline: &mut Pipeline<T>) {
+    stats.identities = pipeline.optimized_pil().unwrap().identities.len();
+    let fixed_cols = pipeline.compute_fixed_cols().unwrap();
+    stats.fixed_columns = fixed_cols.len();
+    for (name, _) in fixed_cols.iter() {
+        stats.machines.entry(machine_of(name).to_string()).or_default().fixed_columns += 1;
+    }
+}
+
+/// Adds the witness columns and rows of each machine in the current witness of the pipeline, that
+/// of a chunk with continuations. A machine's rows are those of its longest column.
+fn add_witness_stats<T: FieldElement>(stats: &mut PowdrStats, pipeline: &Pipeline<T>) {
+    let mut machines: BTreeMap<&str, PowdrMachineStats> = BTreeMap::new();
+    for (name, col) in pipeline.witness().unwrap().iter() {
+        let machine = machines.entry(machine_of(name)).or_default();
+        machine.witness_columns += 1;
+        machine.rows = machine.rows.max(col.len() as u64);
+    }
+    for (name, witness) in machines {
+        let machine = stats.machines.entry(name.to_string()).or_default();
+        machine.witness_columns = witness.witness_columns;
+        machine.rows += witness.rows;
+    }
+}
+
+/// The linker parameters of the configuration.
+fn linker_params(config: &PowdrConfig) -> LinkerParams {
+    LinkerParams {
+        mode: match config.linker {
+            PowdrLinker::Native => LinkerMode::Native,
+            PowdrLinker::Bus => LinkerMode::Bus,
+        },
+        degree_mode: match config.degree_mode {
+            PowdrDegreeMode::Vadcop => DegreeMode::Vadcop,
+            PowdrDegreeMode::Monolithic => DegreeMode::Monolithic,
+        },
+    }
+}
+
+/// The backend of the enabled powdr feature.
+fn backend() -> BackendType {
+    cfg_if! {
+        if #[cfg(feature = "powdr-estark")] {
+            BackendType::EStarkPolygonComposite
+        } else if #[cfg(feature = "powdr-plonky3")] {
+            BackendType::Plonky3
+        } else {
+            unreachable!();
+        }
+    }
+}
+
+/// Sets up the backend, or reads its setup from the `--setup-cache` directory, with the same key as
+/// the fixed columns. The setup is only cached if the backend can export it, which eStark and
+/// Plonky3 may not; otherwise every run computes it, and it is reported as not cached.
+fn setup_backend<T: FieldElement>(
+    args: &EvalArgs,
+    pipeline: &mut Pipeline<T>,
+    program: &[u8],
+    backend_version: &str,
+) -> SetupDurations {
+    let uncached = |pipeline: &mut Pipeline<T>| {
+        let (_, cold) =
+            time_operation(|| pipeline.setup_backend().expect("could not setup the backend"));
+        SetupDurations { cold, ..Default::default() }
+    };
+    if args.setup_cache.is_none() {
+        return uncached(pipeline);
+    }
+
+    let backend_version = format!("{backend_version} backend setup");
+    let (setup, durations) = cached_setup(args, program, &backend_version, || {
+        pipeline.setup_backend().expect("could not setup the backend");
+        let mut setup = vec![];
+        pipeline.export_backend_setup(&mut setup).ok().map(|_| setup)
+    });
+    match setup {
+        // the backend is still created on a hit, from the cached setup, as part of the warm setup
+        Some(setup) if durations.status == CacheStatus::Hit => {
+            let path = PathBuf::from(format!("{OUTPUT_DIR}/backend_setup.bin"));
+            std::fs::write(&path, setup).unwrap();
+            *pipeline = std::mem::take(pipeline).with_setup_file(Some(path));
+            let (_, create_duration) =
+                time_operation(|| pipeline.setup_backend().expect("could not setup the backend"));
+            SetupDurations { warm: durations.warm.map(|warm| warm + create_duration), ..durations }
+        }
+        Some(_) => durations,
+        None => {
+            println!("the {:?} backend has no setup to export, so it is not cached", backend());
+            match durations.status {
+                CacheStatus::Hit => uncached(pipeline),
+                _ => SetupDurations { status: CacheStatus::Off, ..durations },
+            }
+        }
+    }
+}
+
+/// Exports the verification key, to `/tmp/vkey.bin` and, with `--save-proofs`, to the proof
+/// directory along with the optimized PIL, the proof of each chunk and their publics.
+fn export_proofs<T: FieldElement>(
+    args: &EvalArgs,
+    pipeline: &mut Pipeline<T>,
+    proofs: &[Vec<u8>],
+    publics: &[Vec<T>],
+) {
+    let mut vkey = vec![];
+    pipeline.export_verification_key(&mut vkey).unwrap();
+    std::fs::write(format!("{OUTPUT_DIR}/vkey.bin"), &vkey).unwrap();
+
+    let pil = pipeline.optimized_pil().unwrap().to_string();
+    let publics = bincode::serialize(publics).unwrap();
+    let names: Vec<String> =
+        (0..proofs.len()).map(|chunk| format!("chunk_{chunk}_proof.bin")).collect();
+    let mut files = vec![
+        ("program.pil", pil.as_bytes()),
+        ("vkey.bin", vkey.as_slice()),
+        ("publics.bin", publics.as_slice()),
+    ];
+    files.extend(
+        names.iter().map(|name| name.as_str()).zip(proofs.iter().map(|proof| proof.as_slice())),
+    );
+    save_proofs(args, &files);
+}
+
+/// Runs the pipeline without continuations, whose fixed columns were set up in `fixed_setup` and
+/// whose backend is set up by `backend_setup`. The verifier checks `expected_publics`, a list of
+/// public names and values, against the publics of the proof. With `--execute-only`, it stops
+/// after witness generation.
+fn run<T: FieldElement>(
+    args: &EvalArgs,
+    mut pipeline: powdr_pipeline::Pipeline<T>,
+    expected_publics: Vec<(String, T)>,
+    fixed_setup: SetupDurations,
+    backend_setup: impl FnOnce(&mut Pipeline<T>) -> SetupDurations,
+) -> PerformanceReport {
     println!("running powdr with no continuations...");
-    // pre-compute fixed cols
-    pipeline.compute_fixed_cols().expect("error generating fixed columns");
     // compute witness
     let start = Instant::now();
     pipeline.compute_witness().unwrap();
@@ -35,28 +255,51 @@ fn run<T: FieldElement>(mut pipeline: powdr_pipeline::Pipeline<T>) -> Performanc
             .next()
             .unwrap()
     };
+    let mut stats = PowdrStats::default();
+    add_pil_stats(&mut stats, &mut pipeline);
+    add_witness_stats(&mut stats, &pipeline);
+    let mut phases = vec![("witgen".to_string(), witgen_time.as_secs_f64())];
+    if args.execute_only {
+        return PerformanceReport {
+            shards: 1,
+            cycles: trace_len,
+            execution_duration: witgen_time.as_secs_f64(),
+            prover_stats: Some(ProverStats::Powdr(stats)),
+            phases,
+            ..Default::default()
+        };
+    }
 
-    let (_, setup_duration) =
-        time_operation(|| pipeline.setup_backend().expect("could not setup the backend"));
+    let backend_setup = backend_setup(&mut pipeline);
+    let backend_setup_time = backend_setup.warm.unwrap_or(backend_setup.cold);
+    phases.push(("backend setup".to_string(), backend_setup_time.as_secs_f64()));
+    let setup = fixed_setup.and(backend_setup);
 
     // compute proof
     let start = Instant::now();
     pipeline.compute_proof().unwrap();
     let core_proof_duration = start.elapsed();
-    let proof = pipeline.proof().unwrap();
+    let proof = pipeline.proof().unwrap().clone();
     let core_proof_size = proof.len();
     // verify
     let mut pipeline = pipeline.clone();
-    {
-        let mut writer = std::fs::File::create(format!("{OUTPUT_DIR}/vkey.bin")).unwrap();
-        pipeline.export_verification_key(&mut writer).unwrap();
-    }
 
-    let publics = pipeline.publics().unwrap().iter().map(|(_name, v)| v.unwrap()).collect();
+    let named_publics = pipeline.publics().unwrap();
+    for (name, value) in &expected_publics {
+        let (_, public) = named_publics
+            .iter()
+            .find(|(public_name, _)| public_name.ends_with(name.as_str()))
+            .unwrap_or_else(|| panic!("public {name} not found"));
+        assert_eq!(public.unwrap(), *value, "public {name} does not match the expected value");
+    }
+    let publics: Vec<T> = named_publics.iter().map(|(_name, v)| v.unwrap()).collect();
+    export_proofs(args, &mut pipeline, &[proof.clone()], &[publics.clone()]);
 
     let (_, core_verification_time) = time_operation(|| {
-        pipeline.verify(proof, &[publics]).unwrap();
+        pipeline.verify(&proof, &[publics]).unwrap();
     });
+    phases.push(("proof".to_string(), core_proof_duration.as_secs_f64()));
+    phases.push(("verification".to_string(), core_verification_time.as_secs_f64()));
 
     PerformanceReport {
         shards: 1,
@@ -65,34 +308,57 @@ fn run<T: FieldElement>(mut pipeline: powdr_pipeline::Pipeline<T>) -> Performanc
         core_prove_duration: core_proof_duration.as_secs_f64(),
         core_verify_duration: core_verification_time.as_secs_f64(),
         core_proof_size,
-        core_speed: (trace_len as f64) / core_proof_duration.as_secs_f64(),
-        setup_duration: setup_duration.as_secs_f64(),
+        setup_duration: setup.cold.as_secs_f64(),
+        warm_setup_duration: setup.warm.map(|d| d.as_secs_f64()),
+        setup_cache: setup.status,
         // TODO: we don't do recursion/compression yet, so these are all 0
         compress_prove_duration: 0.0,
         compress_verify_duration: 0.0,
         compress_proof_size: 0,
-        speed: 0.0,
-        prove_duration: 0.0,
+        prover_stats: Some(ProverStats::Powdr(stats)),
+        phases,
+        ..Default::default()
     }
 }
 
+/// Runs the pipeline with continuations, whose fixed columns were set up in `fixed_setup` and
+/// whose backend is set up by `backend_setup`. With `--execute-only`, it stops after the dry run,
+/// which splits the execution into chunks.
 fn run_with_continuations<T: FieldElement>(
+    args: &EvalArgs,
     mut pipeline: powdr_pipeline::Pipeline<T>,
+    fixed_setup: SetupDurations,
+    backend_setup: impl FnOnce(&mut Pipeline<T>) -> SetupDurations,
 ) -> PerformanceReport {
     println!("running powdr with continuations...");
-    // pre-compute fixed columns
-    pipeline.compute_fixed_cols().expect("error generating fixed columns");
 
     // execute with continuations
     println!("continuations dry run...");
     let start = Instant::now();
     let dry_run = powdr_riscv::continuations::rust_continuations_dry_run(&mut pipeline, None);
+    let mut phases = vec![("dry run".to_string(), start.elapsed().as_secs_f64())];
 
     let num_chunks = dry_run.bootloader_inputs.len();
     let trace_len = dry_run.trace_len as u64;
+    let mut stats = chunk_stats(&dry_run);
+    let start_pcs: Vec<T> =
+        dry_run.bootloader_inputs.iter().map(|(inputs, _)| inputs[PC_INDEX]).collect();
+    add_pil_stats(&mut stats, &mut pipeline);
+    if args.execute_only {
+        return PerformanceReport {
+            shards: num_chunks,
+            cycles: trace_len,
+            execution_duration: start.elapsed().as_secs_f64(),
+            prover_stats: Some(ProverStats::Powdr(stats)),
+            phases,
+            ..Default::default()
+        };
+    }
 
+    let chunk_witgen_times = RefCell::new(vec![]);
     let generate_witness = |pipeline: &mut Pipeline<T>| -> Result<(), Vec<String>> {
-        pipeline.compute_witness().unwrap();
+        let (_, time) = time_operation(|| pipeline.compute_witness().unwrap());
+        chunk_witgen_times.borrow_mut().push(time);
         Ok(())
     };
     // this will save the witness for each chunk N in its own `chunk_N` directory
@@ -101,9 +367,14 @@ fn run_with_continuations<T: FieldElement>(
         .expect("error executing with continuations");
     let witgen_time = start.elapsed();
     println!("continuations witgen time: {witgen_time:?}");
+    for (chunk, time) in chunk_witgen_times.into_inner().into_iter().enumerate() {
+        phases.push((format!("chunk {chunk} witgen"), time.as_secs_f64()));
+    }
 
-    let (_, setup_duration) =
-        time_operation(|| pipeline.setup_backend().expect("could not setup the backend"));
+    let backend_setup = backend_setup(&mut pipeline);
+    let backend_setup_time = backend_setup.warm.unwrap_or(backend_setup.cold);
+    phases.push(("backend setup".to_string(), backend_setup_time.as_secs_f64()));
+    let setup = fixed_setup.and(backend_setup);
 
     // compute proof for each chunk
     let mut core_proof_duration = Duration::default();
@@ -111,11 +382,15 @@ fn run_with_continuations<T: FieldElement>(
     let mut proofs = vec![];
     println!("proving chunks...");
     let mut publics: Vec<Vec<_>> = vec![];
+    let mut chunk_bootloader_rows = vec![];
     for chunk in 0..num_chunks {
         let witness_dir: PathBuf = format!("{OUTPUT_DIR}/chunk_{chunk}").into();
         pipeline = pipeline.read_witness(&witness_dir).unwrap().with_output(witness_dir, true);
+        add_witness_stats(&mut stats, &pipeline);
+        chunk_bootloader_rows.push(bootloader_rows(&pipeline, start_pcs[chunk]));
         let (proof, chunk_duration) = time_operation(|| pipeline.compute_proof().unwrap().clone());
         println!("chunk {chunk} proof time: {chunk_duration:?}");
+        phases.push((format!("chunk {chunk} proof"), chunk_duration.as_secs_f64()));
         let chunk_size = proof.len();
         publics.push(pipeline.publics().unwrap().iter().map(|(_name, v)| v.unwrap()).collect());
         proofs.push(proof);
@@ -123,16 +398,14 @@ fn run_with_continuations<T: FieldElement>(
         core_proof_duration += chunk_duration;
         core_proof_size += chunk_size;
     }
+    add_bootloader_stats(&mut stats, chunk_bootloader_rows);
     println!("total proof time: {core_proof_duration:?}");
     println!("total proof size: {core_proof_size}");
 
     // verify each chunk
     let mut core_verification_time = Duration::default();
     println!("exporting verification key...");
-    {
-        let mut writer = std::fs::File::create(format!("{OUTPUT_DIR}/vkey.bin")).unwrap();
-        pipeline.export_verification_key(&mut writer).unwrap();
-    }
+    export_proofs(args, &mut pipeline, &proofs, &publics);
     println!("verifying chunks...");
     for chunk in 0..num_chunks {
         let (_, time) = time_operation(|| {
@@ -141,6 +414,7 @@ fn run_with_continuations<T: FieldElement>(
         println!("chunk {chunk} verification time: {time:?}");
         core_verification_time += time;
     }
+    phases.push(("verification".to_string(), core_verification_time.as_secs_f64()));
 
     PerformanceReport {
         shards: num_chunks,
@@ -149,34 +423,40 @@ fn run_with_continuations<T: FieldElement>(
         core_prove_duration: core_proof_duration.as_secs_f64(),
         core_verify_duration: core_verification_time.as_secs_f64(),
         core_proof_size,
-        core_speed: (trace_len as f64) / core_proof_duration.as_secs_f64(),
-        setup_duration: setup_duration.as_secs_f64(),
+        setup_duration: setup.cold.as_secs_f64(),
+        warm_setup_duration: setup.warm.map(|d| d.as_secs_f64()),
+        setup_cache: setup.status,
         // TODO: we don't do recursion/compression yet, so these are all 0
         compress_prove_duration: 0.0,
         compress_verify_duration: 0.0,
         compress_proof_size: 0,
-        speed: 0.0,
-        prove_duration: 0.0,
+        prover_stats: Some(ProverStats::Powdr(stats)),
+        phases,
+        ..Default::default()
     }
 }
 
 impl PowdrEvaluator {
     pub fn eval(args: &EvalArgs) -> PerformanceReport {
-        assert!(args.hashfn == HashFnId::Poseidon);
+        assert!(args.prover.supports_hashfn(&args.hashfn));
 
         // setup logger
         let mut builder = env_logger::Builder::new();
         builder.parse_default_env().target(env_logger::Target::Stdout).init();
 
+        let config = PowdrConfig::new(args);
+        assert!(prover_opts(args).is_empty(), "powdr has no prover options");
+
         // generate powdr asm
+        let start = Instant::now();
         let (path, asm) = match &args.program {
             ProgramId::Tendermint => {
-                let path = format!("programs/{}-powdr", args.program.to_string());
-                compile_program::<GoldilocksField>(path, args.shard_size, true).unwrap()
+                let path = format!("programs/{}-powdr", args.program);
+                compile_program::<GoldilocksField>(args, path, config.max_degree_log, true)
             }
             ProgramId::Reth => {
-                let path = format!("programs/{}-powdr", args.program.to_string());
-                compile_program::<GoldilocksField>(path, args.shard_size, true).unwrap()
+                let path = format!("programs/{}-powdr", args.program);
+                compile_program::<GoldilocksField>(args, path, config.max_degree_log, true)
             }
             ProgramId::BrainfuckAsm => {
                 let path = format!("programs/brainfuck/brainfuck_vm.asm").into();
@@ -184,9 +464,13 @@ impl PowdrEvaluator {
                     std::fs::read_to_string(&path).expect("error reading brainfuck powdr asm file");
                 (Some(path), asm)
             }
-            ProgramId::BrainfuckCompiler => {
+            ProgramId::BrainfuckCompiler | ProgramId::BrainfuckCompilerOpt => {
                 let (program, _) = get_brainfuck_input(args);
-                let bf_asm = compile_brainfuck(&program[..]);
+                let bf_asm = if args.program == ProgramId::BrainfuckCompilerOpt {
+                    compile_brainfuck_optimized(&program)
+                } else {
+                    compile_brainfuck(&program)
+                };
                 let path = "programs/brainfuck/brainfuck_isa.asm";
                 let bf_isa = std::fs::read_to_string(path).unwrap();
                 let bf_vm = bf_isa.replace("{{ program }}", bf_asm.as_str());
@@ -194,52 +478,57 @@ impl PowdrEvaluator {
                 (Some(path.into()), bf_vm)
             }
             program => {
-                let path = format!("programs/{}", program.to_string());
+                let path = format!("programs/{program}");
                 compile_program::<GoldilocksField>(
+                    args,
                     path,
-                    args.shard_size,
-                    !args.powdr_no_continuations,
+                    config.max_degree_log,
+                    config.continuations,
                 )
-                .unwrap()
             }
         };
+        let compilation_time = start.elapsed();
 
         let dir = "/tmp";
 
         // build the powdr pipeline
+        let backend = backend();
+        println!("using {backend:?} backend");
         cfg_if! {
             if #[cfg(feature = "powdr-estark")] {
-                println!("using EStark backend");
                 assert!(args.prover == ProverId::PowdrEstark);
-                let backend = powdr_pipeline::BackendType::EStarkPolygonComposite;
             } else if #[cfg(feature = "powdr-plonky3")] {
-                println!("using Plonky3 backend");
                 assert!(args.prover == ProverId::PowdrPlonky3);
-                let backend = powdr_pipeline::BackendType::Plonky3;
-            } else {
-                unreachable!();
             }
         }
-        let mut pipeline = powdr_pipeline::Pipeline::<GoldilocksField>::default()
-            .from_asm_string(asm, path)
+        // capture the output with the first callback, before the program and inputs add theirs
+        let (pipeline, output) = capture_output(Pipeline::<GoldilocksField>::default());
+        let mut pipeline = pipeline
+            .from_asm_string(asm.clone(), path)
             .with_output(dir.into(), true)
             .with_prover_inputs(vec![])
-            .with_linker_params(LinkerParams {
-                mode: LinkerMode::Native,
-                degree_mode: DegreeMode::Vadcop,
-            })
+            .with_linker_params(linker_params(&config))
             // .with_setup_file()
             // .with_pil_object()
             .with_backend(backend, None);
 
         // set program inputs
+        let mut expected_publics = vec![];
         match args.program {
             ProgramId::Brainfuck => {
                 let (program, input) = get_brainfuck_input(args);
-                pipeline = pipeline.add_data(0, &program).add_data(1, &input)
+                pipeline = pipeline.add_data(0, &program.encode()).add_data(1, &input)
             }
             ProgramId::BrainfuckAsm => {
                 let (program, input) = get_brainfuck_input(args);
+                let program = encode_brainfuck_vm_program(&program);
+                // the verifier checks the program commitment against the program read here
+                let commitment = brainfuck_program_commitment::<GoldilocksField>(&program);
+                expected_publics = commitment
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, value)| (format!("program_hash_{i}"), value))
+                    .collect();
                 let prover_inputs = std::iter::once(program.len() as u32)
                     .chain(program.into_iter())
                     .chain(std::iter::once(input.len() as u32))
@@ -248,12 +537,15 @@ impl PowdrEvaluator {
                     .collect();
                 pipeline = pipeline.with_prover_inputs(prover_inputs);
             }
-            ProgramId::BrainfuckCompiler => {
+            ProgramId::BrainfuckCompiler | ProgramId::BrainfuckCompilerOpt => {
                 let (_, input) = get_brainfuck_input(args);
                 let prover_inputs =
                     std::iter::once(input.len() as u32).chain(input).map(|n| n.into()).collect();
                 pipeline = pipeline.with_prover_inputs(prover_inputs);
             }
+            ProgramId::SSZWithdrawals => {
+                pipeline = pipeline.add_data(0, &get_ssz_withdrawals_input(args));
+            }
             ProgramId::Reth => {
                 let data = vec![(0, get_reth_input(args))];
                 pipeline = pipeline.add_data_vec(&data[..]);
@@ -261,37 +553,129 @@ impl PowdrEvaluator {
             _ => {}
         }
 
-        // run the pipeline
-        match args.program {
-            // non-riscv programs can't run with continuations
-            ProgramId::BrainfuckAsm | ProgramId::BrainfuckCompiler => run(pipeline),
-            _ => {
-                if args.powdr_no_continuations {
-                    run(pipeline)
-                } else {
-                    run_with_continuations(pipeline)
+        // analyse, link and optimise the PIL, which the fixed columns are computed from
+        let (_, pil_time) = time_operation(|| {
+            pipeline.compute_optimized_pil().expect("error optimizing the PIL");
+        });
+
+        // pre-compute the fixed columns, or read them from the setup cache; they depend on how the
+        // machines are linked and sized
+        let backend_version =
+            format!("{POWDR_VERSION} {backend:?} {} {}", config.linker, config.degree_mode);
+        let (fixed_cols, fixed_setup) =
+            cached_setup(args, asm.as_bytes(), &backend_version, || {
+                pipeline
+                    .compute_fixed_cols()
+                    .expect("error generating fixed columns")
+                    .as_ref()
+                    .clone()
+            });
+        pipeline.set_fixed_cols(fixed_cols);
+        // the time this run spent on the fixed columns, reading them on a cache hit
+        let fixed_cols_time = fixed_setup.warm.unwrap_or(fixed_setup.cold);
+
+        // run the pipeline, which sets up the backend once the witness is computed
+        let backend_setup = |pipeline: &mut Pipeline<GoldilocksField>| {
+            setup_backend(args, pipeline, asm.as_bytes(), &backend_version)
+        };
+        let mut report = if config.continuations {
+            run_with_continuations(args, pipeline, fixed_setup, backend_setup)
+        } else {
+            run(args, pipeline, expected_publics, fixed_setup, backend_setup)
+        };
+        report.prover_config = Some(ProverConfig::Powdr(config));
+        let mut phases = vec![
+            ("asm compilation".to_string(), compilation_time.as_secs_f64()),
+            ("pil optimization".to_string(), pil_time.as_secs_f64()),
+            ("fixed columns".to_string(), fixed_cols_time.as_secs_f64()),
+        ];
+        phases.append(&mut report.phases);
+        report.phases = phases;
+
+        // as for the other provers, the output is only checked when the program was proven
+        if !args.execute_only {
+            let output = output.lock().unwrap();
+            match args.program {
+                // the RISC-V interpreter prints its output with a trailing newline
+                ProgramId::Brainfuck => {
+                    check_brainfuck_output(args, output.strip_suffix(b"\n").unwrap_or(&output[..]))
                 }
+                ProgramId::BrainfuckAsm |
+                ProgramId::BrainfuckCompiler |
+                ProgramId::BrainfuckCompilerOpt => check_brainfuck_output(args, &output),
+                _ => {}
             }
         }
+
+        report
+    }
+}
+
+/// Verifies the proofs saved by [`PowdrEvaluator::eval`] with `--save-proofs`, against the saved
+/// PIL and verification key.
+pub fn verify_proofs(dir: &Path, manifest: &Manifest) -> Vec<(String, Duration)> {
+    let pil = String::from_utf8(read_proof_file(dir, "program.pil")).unwrap();
+    let publics: Vec<Vec<GoldilocksField>> =
+        bincode::deserialize(&read_proof_file(dir, "publics.bin")).unwrap();
+
+    let mut pipeline = Pipeline::<GoldilocksField>::default()
+        .from_pil_string(pil)
+        .with_backend(backend(), None)
+        .with_vkey_file(Some(dir.join("vkey.bin")));
+    let (_, setup_duration) = time_operation(|| {
+        pipeline.compute_fixed_cols().expect("error generating fixed columns");
+        pipeline.setup_backend().expect("could not setup the backend");
+    });
+    let mut durations = vec![("setup".to_string(), setup_duration)];
+
+    let chunks = manifest.files.iter().filter(|file| file.starts_with("chunk_")).count();
+    assert_eq!(chunks, publics.len(), "expected the publics of every chunk");
+    for (chunk, publics) in publics.into_iter().enumerate() {
+        let proof = read_proof_file(dir, &format!("chunk_{chunk}_proof.bin"));
+        let (_, verify_duration) = time_operation(|| {
+            pipeline
+                .verify(&proof, &[publics])
+                .unwrap_or_else(|err| panic!("chunk {chunk} verification failed: {err:?}"))
+        });
+        durations.push((format!("chunk {chunk}"), verify_duration));
     }
+    durations
 }
 
+/// Compiles a guest crate to powdr asm, or reads the asm from the `--setup-cache` directory if the
+/// crate, the compiler options and the powdr version did not change.
 fn compile_program<F: FieldElement>(
+    args: &EvalArgs,
+    crate_path: String,
+    max_degree_log: u8,
+    with_continuations: bool,
+) -> (Option<PathBuf>, String) {
+    let known_field = F::known_field().unwrap();
+    let options = format!(
+        "{POWDR_VERSION} {known_field:?} max_degree_log={max_degree_log} \
+         continuations={with_continuations} features=powdr"
+    );
+    // the programs are found relative to the workspace root, which the benchmarks run from
+    let digest = crate_digest(Path::new("."), Path::new(&crate_path));
+    cached_build(args, "powdr-asm", &[options.as_bytes(), &digest], || {
+        compile_rust_program::<F>(crate_path, max_degree_log, with_continuations)
+            .expect("error compiling the program to powdr asm")
+    })
+}
+
+fn compile_rust_program<F: FieldElement>(
     crate_path: String,
-    shard_size: u64,
+    max_degree_log: u8,
     with_continuations: bool,
 ) -> Option<(Option<PathBuf>, String)> {
     println!("compiling {} (continuations={with_continuations})...", crate_path.to_string());
 
-    let max_degree_log = shard_size;
-
     let output_dir: PathBuf = OUTPUT_DIR.into();
     let force_overwrite = true;
     let known_field = F::known_field().unwrap();
     let options = match known_field {
         KnownField::GoldilocksField => {
-            let opt =
-                powdr_riscv::CompilerOptions::new_gl().with_max_degree_log(max_degree_log as u8);
+            let opt = powdr_riscv::CompilerOptions::new_gl().with_max_degree_log(max_degree_log);
             if with_continuations {
                 opt.with_continuations()
             } else {
@@ -0,0 +1,137 @@
+//! Saving the proofs of a run with `--save-proofs DIR`, and re-verifying them in a fresh process
+//! with the `verify` subcommand.
+//!
+//! A proof directory holds a `manifest.json` describing the run and listing the files, and the
+//! files themselves, which depend on the prover:
+//!
+//! - SP1: `core_proof.bin` and `compressed_proof.bin` (bincode `SP1CoreProof` and
+//!   `SP1ReduceProof`), `vkey.bin` (bincode `SP1VerifyingKey`) and `public_values.bin` (raw bytes).
+//! - RISC0: `core_receipt.bin` and `compressed_receipt.bin` (bincode `Receipt`), `image_id.bin`
+//!   (bincode `Digest`) and `journal.bin` (raw bytes).
+//! - powdr: `program.pil` (the optimized PIL), `vkey.bin`, one `chunk_<i>_proof.bin` per chunk (a
+//!   single chunk without continuations) and `publics.bin` (bincode publics of each chunk).
+//!
+//! The compressed proofs are missing for runs with `--core-only`.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use cfg_if::cfg_if;
+use clap::{Args, ValueEnum};
+use serde::{Deserialize, Serialize};
+
+use crate::{EvalArgs, ProverId};
+
+/// The arguments of the `verify` subcommand.
+#[derive(Args, Clone)]
+pub struct VerifyArgs {
+    /// The directory the proofs were saved to with `--save-proofs`.
+    dir: PathBuf,
+}
+
+/// The description of a proof directory.
+#[derive(Serialize, Deserialize)]
+pub struct Manifest {
+    pub program: String,
+    pub program_args: String,
+    pub prover: String,
+    pub hashfn: String,
+    pub shard_size: u64,
+    /// The files of the directory.
+    pub files: Vec<String>,
+}
+
+/// Writes the proof files of a run to the `--save-proofs` directory, if one was given, along with
+/// its manifest.
+pub fn save_proofs(args: &EvalArgs, files: &[(&str, &[u8])]) {
+    let Some(dir) = &args.save_proofs else {
+        return;
+    };
+    fs::create_dir_all(dir).unwrap();
+    for (name, content) in files {
+        fs::write(dir.join(name), content)
+            .unwrap_or_else(|_| panic!("Failed to write {:?}", dir.join(name)));
+    }
+    let manifest = Manifest {
+        program: args.program.to_string(),
+        program_args: args.program_args(),
+        prover: args.prover.to_string(),
+        hashfn: args.hashfn.to_string(),
+        shard_size: args.shard_size,
+        files: files.iter().map(|(name, _)| name.to_string()).collect(),
+    };
+    fs::write(dir.join("manifest.json"), serde_json::to_string_pretty(&manifest).unwrap()).unwrap();
+    println!("saved the proofs to {}", dir.display());
+}
+
+/// Reads a file of a proof directory.
+pub fn read_proof_file(dir: &Path, name: &str) -> Vec<u8> {
+    fs::read(dir.join(name)).unwrap_or_else(|_| panic!("Failed to read {:?}", dir.join(name)))
+}
+
+/// Verifies the proofs of a directory, printing the time of each step: the verifier setup and the
+/// verification of each proof.
+pub fn run(args: &VerifyArgs) {
+    let manifest: Manifest = serde_json::from_slice(&read_proof_file(&args.dir, "manifest.json"))
+        .expect("Invalid proof manifest");
+    println!(
+        "verifying the proofs of {} {} on {} ({}, shard size {})",
+        manifest.program,
+        manifest.program_args,
+        manifest.prover,
+        manifest.hashfn,
+        manifest.shard_size
+    );
+    let prover = ProverId::from_str(&manifest.prover, false)
+        .unwrap_or_else(|_| panic!("Unknown prover {}", manifest.prover));
+    let durations: Vec<(String, Duration)> = match prover {
+        ProverId::Risc0 => {
+            cfg_if! {
+                if #[cfg(feature = "risc0")] {
+                    crate::risc0::verify_proofs(&args.dir, &manifest)
+                } else {
+                    panic!("Verifying RISC0 proofs requires the risc0 feature")
+                }
+            }
+        }
+        ProverId::SP1 => {
+            cfg_if! {
+                if #[cfg(feature = "sp1")] {
+                    crate::sp1::verify_proofs(&args.dir, &manifest)
+                } else {
+                    panic!("Verifying SP1 proofs requires the sp1 feature")
+                }
+            }
+        }
+        ProverId::PowdrEstark => {
+            cfg_if! {
+                if #[cfg(feature = "powdr-estark")] {
+                    crate::powdr::verify_proofs(&args.dir, &manifest)
+                } else {
+                    panic!("Verifying powdr-estark proofs requires the powdr-estark feature")
+                }
+            }
+        }
+        ProverId::PowdrPlonky3 => {
+            cfg_if! {
+                if #[cfg(feature = "powdr-plonky3")] {
+                    crate::powdr::verify_proofs(&args.dir, &manifest)
+                } else {
+                    panic!("Verifying powdr-plonky3 proofs requires the powdr-plonky3 feature")
+                }
+            }
+        }
+    };
+
+    // Every step ran in this process, so these are cold-start times.
+    println!("| step | duration (s) |");
+    println!("|------|--------------|");
+    for (step, duration) in &durations {
+        println!("| {} | {:.3} |", step, duration.as_secs_f64());
+    }
+    let total: Duration = durations.iter().map(|(_, duration)| *duration).sum();
+    println!("| total | {:.3} |", total.as_secs_f64());
+}
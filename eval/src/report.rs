@@ -0,0 +1,629 @@
+//! The `report` subcommand, turning results files into tables comparing the provers.
+
+use std::{collections::BTreeMap, fs, path::PathBuf};
+
+use clap::Args;
+
+use crate::{
+    chart::{BarChart, LineChart, Scale, Series},
+    results::{read_results, ResultRow},
+    ProverId,
+};
+
+/// The arguments of the `report` subcommand.
+#[derive(Args, Clone)]
+pub struct ReportArgs {
+    /// The results files to read.
+    #[arg(required = true)]
+    files: Vec<PathBuf>,
+    /// The prover that the ratios are relative to.
+    #[arg(long, default_value = "sp1")]
+    baseline: ProverId,
+    /// The path of the report, to which `.md` and `.html` are appended. The charts are written
+    /// next to it, as `<output>_<chart>.svg`.
+    #[arg(long, default_value = "benchmarks/report")]
+    output: PathBuf,
+}
+
+/// How the values of a metric are formatted.
+#[derive(Clone, Copy)]
+enum Unit {
+    Count,
+    Seconds,
+    Bytes,
+    /// Cycles per second.
+    Speed,
+}
+
+/// The metrics shown in the tables, as column name, label and unit.
+const METRICS: &[(&str, &str, Unit)] = &[
+    ("cycles", "cycles", Unit::Count),
+    ("shards", "shards", Unit::Count),
+    ("execution_duration", "execution", Unit::Seconds),
+    ("setup_duration", "setup", Unit::Seconds),
+    ("warm_setup_duration", "warm setup", Unit::Seconds),
+    ("core_prove_duration", "core proving", Unit::Seconds),
+    ("compress_prove_duration", "compression", Unit::Seconds),
+    ("prove_duration", "proving", Unit::Seconds),
+    ("core_verify_duration", "core verification", Unit::Seconds),
+    ("core_speed", "core throughput", Unit::Speed),
+    ("speed", "end-to-end throughput", Unit::Speed),
+    ("core_proof_size", "core proof size", Unit::Bytes),
+    ("compress_proof_size", "compressed proof size", Unit::Bytes),
+    ("peak_memory", "peak memory", Unit::Bytes),
+];
+
+/// A cell of a table.
+enum Cell {
+    /// The mean over the successful trials, and its ratio to the baseline.
+    Value(f64, Option<f64>),
+    /// All trials of the prover failed.
+    Failed,
+    /// The prover does not support the configuration.
+    Unsupported,
+    /// The prover was not run, or did not report the metric.
+    Missing,
+    /// A value that is not a metric, such as the configuration of the prover.
+    Text(String),
+}
+
+/// A chart of the report, with the name of its file.
+struct Chart {
+    title: String,
+    file_name: String,
+    svg: String,
+}
+
+/// A table comparing the provers on a program and input, with one configuration.
+struct Table {
+    title: String,
+    /// The label, unit and cells of each metric, with the cells in the order of the provers.
+    rows: Vec<(&'static str, Unit, Vec<Cell>)>,
+}
+
+pub fn run(args: &ReportArgs) {
+    let results: Vec<ResultRow> = args.files.iter().flat_map(|path| read_results(path)).collect();
+    let baseline = args.baseline.to_string();
+
+    let provers = provers(&results);
+    let tables = tables(&results, &provers, &baseline);
+
+    let stem = args.output.file_name().expect("Invalid report path").to_string_lossy();
+    let charts = charts(&results, &provers, &stem);
+
+    let markdown_path = args.output.with_extension("md");
+    let html_path = args.output.with_extension("html");
+    if let Some(dir) = args.output.parent() {
+        fs::create_dir_all(dir).unwrap();
+    }
+    for chart in &charts {
+        fs::write(args.output.with_file_name(&chart.file_name), &chart.svg).unwrap();
+    }
+    fs::write(&markdown_path, render_markdown(&tables, &charts, &provers, &baseline)).unwrap();
+    fs::write(&html_path, render_html(&tables, &charts, &provers, &baseline)).unwrap();
+    println!(
+        "wrote {} and {} with {} charts",
+        markdown_path.display(),
+        html_path.display(),
+        charts.len()
+    );
+}
+
+/// The provers in the order they first appear in the results. Every prover gets a column in
+/// every table, so that missing runs stand out.
+fn provers(results: &[ResultRow]) -> Vec<String> {
+    let mut provers: Vec<String> = vec![];
+    for row in results {
+        if !provers.contains(&row.prover) {
+            provers.push(row.prover.clone());
+        }
+    }
+    provers
+}
+
+/// Groups the runs by program, input and configuration, with a table for each.
+fn tables(results: &[ResultRow], provers: &[String], baseline: &str) -> Vec<Table> {
+    let mut groups: BTreeMap<_, Vec<&ResultRow>> = BTreeMap::new();
+    for row in results {
+        let key = (&row.program, &row.program_args, &row.hashfn, row.shard_size);
+        groups.entry(key).or_default().push(row);
+    }
+    groups
+        .into_iter()
+        .map(|((program, program_args, hashfn, shard_size), rows)| {
+            let mut title = program.clone();
+            if !program_args.is_empty() {
+                title += &format!(" {program_args}");
+            }
+            title += &format!(" ({hashfn}, shard size {shard_size})");
+            Table { title, rows: compare(&rows, provers, baseline) }
+        })
+        .collect()
+}
+
+/// The mean of a metric over the successful trials among `rows`.
+fn mean(rows: &[&ResultRow], metric: &str) -> Option<f64> {
+    let values: Vec<f64> =
+        rows.iter().filter(|row| !row.failed()).filter_map(|row| row.metric(metric)).collect();
+    (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+}
+
+/// Draws the scaling charts: throughput against cycles over all runs, core proving time and peak
+/// memory against shard size for each program and input, and the proof sizes of each program.
+fn charts(results: &[ResultRow], provers: &[String], stem: &str) -> Vec<Chart> {
+    let mut charts = vec![];
+
+    // a point per program, input and configuration
+    let series = provers
+        .iter()
+        .map(|prover| {
+            let mut runs: BTreeMap<_, Vec<&ResultRow>> = BTreeMap::new();
+            for row in results.iter().filter(|row| &row.prover == prover) {
+                runs.entry(row.key()).or_default().push(row);
+            }
+            let points = runs
+                .values()
+                .filter_map(|rows| Some((mean(rows, "cycles")?, mean(rows, "speed")?)))
+                .collect();
+            Series { name: prover.clone(), points }
+        })
+        .collect();
+    let chart = LineChart {
+        title: "End-to-end throughput vs cycles".to_string(),
+        x_label: "cycles".to_string(),
+        y_label: "end-to-end throughput (cycles/s)".to_string(),
+        x_scale: Scale::Log,
+        lines: false,
+        series,
+    };
+    charts.push(Chart {
+        title: chart.title.clone(),
+        file_name: format!("{stem}_throughput.svg"),
+        svg: chart.render(),
+    });
+
+    let mut workloads: BTreeMap<_, Vec<&ResultRow>> = BTreeMap::new();
+    for row in results {
+        workloads.entry((&row.program, &row.program_args, &row.hashfn)).or_default().push(row);
+    }
+    let workload_name = |program: &str, program_args: &str| {
+        if program_args.is_empty() {
+            program.to_string()
+        } else {
+            format!("{program} {program_args}")
+        }
+    };
+    for ((program, program_args, hashfn), rows) in &workloads {
+        let workload = workload_name(program, program_args);
+        let file_name: String = format!("{workload} {hashfn}")
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '_' })
+            .collect();
+        // the peak memory is shown in GiB, which the tick labels do not have a suffix for
+        let sweeps = [
+            (
+                "Core proving time",
+                "core proving time (s)",
+                "core_prove_duration",
+                1.0,
+                "prove_time",
+            ),
+            ("Peak memory", "peak memory (GiB)", "peak_memory", (1u64 << 30) as f64, "memory"),
+        ];
+        for (title, y_label, metric, unit, chart_name) in sweeps {
+            let series: Vec<Series> = provers
+                .iter()
+                .map(|prover| {
+                    let mut shard_sizes: BTreeMap<u64, Vec<&ResultRow>> = BTreeMap::new();
+                    for row in rows.iter().filter(|row| &row.prover == prover) {
+                        shard_sizes.entry(row.shard_size).or_default().push(row);
+                    }
+                    let points = shard_sizes
+                        .into_iter()
+                        .filter_map(|(shard_size, rows)| {
+                            let value = mean(&rows, metric).filter(|&value| value > 0.0)?;
+                            Some((shard_size as f64, value / unit))
+                        })
+                        .collect();
+                    Series { name: prover.clone(), points }
+                })
+                .collect();
+            // a single shard size makes no curve
+            if series.iter().all(|series| series.points.len() < 2) {
+                continue;
+            }
+            let chart = LineChart {
+                title: format!("{title} vs shard size: {workload} ({hashfn})"),
+                x_label: "--shard-size".to_string(),
+                y_label: y_label.to_string(),
+                x_scale: Scale::Linear,
+                lines: true,
+                series,
+            };
+            charts.push(Chart {
+                title: chart.title.clone(),
+                file_name: format!("{stem}_{chart_name}_{file_name}.svg"),
+                svg: chart.render(),
+            });
+        }
+    }
+
+    // a group of bars per program and input, averaged over the configurations, with a bar per
+    // prover
+    let categories: Vec<_> = workloads
+        .iter()
+        .map(|((program, program_args, hashfn), rows)| {
+            (format!("{}\n{hashfn}", workload_name(program, program_args)), rows)
+        })
+        .collect();
+    for (kind, metric) in [("Core", "core_proof_size"), ("Compressed", "compress_proof_size")] {
+        let series: Vec<(String, Vec<Option<f64>>)> = provers
+            .iter()
+            .map(|prover| {
+                let values = categories
+                    .iter()
+                    .map(|(_, rows)| {
+                        let runs: Vec<&ResultRow> =
+                            rows.iter().filter(|row| &row.prover == prover).copied().collect();
+                        mean(&runs, metric).filter(|&size| size > 0.0)
+                    })
+                    .collect();
+                (prover.clone(), values)
+            })
+            .collect();
+        if series.iter().all(|(_, values)| values.iter().all(Option::is_none)) {
+            continue;
+        }
+        let chart = BarChart {
+            title: format!("{kind} proof size per program"),
+            y_label: "proof size (bytes)".to_string(),
+            categories: categories.iter().map(|(name, _)| name.clone()).collect(),
+            series,
+        };
+        charts.push(Chart {
+            title: chart.title.clone(),
+            file_name: format!("{stem}_{metric}.svg"),
+            svg: chart.render(),
+        });
+    }
+    charts
+}
+
+/// Computes the cells of a table from the runs of one program, input and configuration.
+fn compare(
+    rows: &[&ResultRow],
+    provers: &[String],
+    baseline: &str,
+) -> Vec<(&'static str, Unit, Vec<Cell>)> {
+    let by_prover = |prover: &str| -> Vec<&ResultRow> {
+        rows.iter().filter(|row| row.prover == prover).copied().collect()
+    };
+
+    // the JSON columns are shown as text, from the first run that recorded them
+    let text_cells = |column: fn(&ResultRow) -> &str| -> Vec<Cell> {
+        provers
+            .iter()
+            .map(|prover| {
+                let runs = by_prover(prover);
+                match runs.iter().find(|row| !matches!(column(row), "" | "null" | "{}" | "[]")) {
+                    // the prover cannot record the column at all, e.g. powdr's cycle regions
+                    Some(row) if column(row) == "unsupported" => Cell::Unsupported,
+                    Some(row) => Cell::Text(format_config(column(row))),
+                    None if !runs.is_empty() && runs.iter().all(|row| row.unsupported()) => {
+                        Cell::Unsupported
+                    }
+                    None => Cell::Missing,
+                }
+            })
+            .collect()
+    };
+    // the parameters behind the shard size differ between the provers, so they are shown too
+    let configurations = text_cells(|row| &row.prover_config);
+    let statistics = text_cells(|row| &row.prover_stats);
+    let cycle_regions = text_cells(|row| &row.cycle_regions);
+    let phases = text_cells(|row| &row.phases);
+    let metrics = METRICS.iter().map(|&(metric, label, unit)| {
+        let baseline_value = mean(&by_prover(baseline), metric).filter(|&value| value > 0.0);
+        let cells: Vec<Cell> = provers
+            .iter()
+            .map(|prover| {
+                let runs = by_prover(prover);
+                if runs.is_empty() {
+                    Cell::Missing
+                } else if runs.iter().all(|row| row.unsupported()) {
+                    Cell::Unsupported
+                } else if runs.iter().all(|row| row.failed()) {
+                    Cell::Failed
+                } else {
+                    match mean(&runs, metric) {
+                        Some(value) => {
+                            let ratio =
+                                baseline_value.filter(|_| prover != baseline).map(|b| value / b);
+                            Cell::Value(value, ratio)
+                        }
+                        None => Cell::Missing,
+                    }
+                }
+            })
+            .collect();
+        (label, unit, cells)
+    });
+    let mut table: Vec<_> = std::iter::once(("configuration", Unit::Count, configurations))
+        .chain(metrics)
+        .chain([
+            ("statistics", Unit::Count, statistics),
+            ("cycle regions", Unit::Count, cycle_regions),
+            ("phases", Unit::Seconds, phases),
+        ])
+        .collect();
+
+    // rows that no prover reported, such as the warm setup without a setup cache, are left out,
+    // unless nothing was reported at all
+    let reported = |(_, _, cells): &(&str, Unit, Vec<Cell>)| {
+        cells.iter().any(|cell| matches!(cell, Cell::Value(..) | Cell::Text(_)))
+    };
+    if table.iter().any(reported) {
+        table.retain(reported);
+    }
+    table
+}
+
+/// Formats the JSON configuration or statistics of a prover as `key=value` pairs, and the phases
+/// of a run, a list of names and durations, as `name=1.23 s` in order.
+fn format_config(config: &str) -> String {
+    if let Ok(phases) = serde_json::from_str::<Vec<(String, f64)>>(config) {
+        return phases
+            .iter()
+            .map(|(name, duration)| format!("{name}={}", format_value(*duration, Unit::Seconds)))
+            .collect::<Vec<_>>()
+            .join(", ");
+    }
+    match serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(config) {
+        Ok(fields) => fields
+            .iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<_>>()
+            .join(", "),
+        Err(_) => config.to_string(),
+    }
+}
+
+fn format_value(value: f64, unit: Unit) -> String {
+    match unit {
+        Unit::Count => format!("{value:.0}"),
+        Unit::Seconds => format!("{value:.2} s"),
+        Unit::Bytes if value >= 1024.0 * 1024.0 * 1024.0 => {
+            format!("{:.2} GiB", value / 1024.0 / 1024.0 / 1024.0)
+        }
+        Unit::Bytes if value >= 1024.0 * 1024.0 => format!("{:.2} MiB", value / 1024.0 / 1024.0),
+        Unit::Bytes if value >= 1024.0 => format!("{:.1} KiB", value / 1024.0),
+        Unit::Bytes => format!("{value:.0} B"),
+        Unit::Speed if value >= 1e6 => format!("{:.2} MHz", value / 1e6),
+        Unit::Speed => format!("{:.1} kHz", value / 1e3),
+    }
+}
+
+/// Formats a cell as its text and, for highlighting, its kind.
+fn format_cell(cell: &Cell, unit: Unit) -> (String, &'static str) {
+    match cell {
+        Cell::Value(value, Some(ratio)) => {
+            (format!("{} ({ratio:.2}x)", format_value(*value, unit)), "value")
+        }
+        Cell::Value(value, None) => (format_value(*value, unit), "value"),
+        Cell::Failed => ("failed".to_string(), "failed"),
+        Cell::Unsupported => ("unsupported".to_string(), "unsupported"),
+        Cell::Missing => ("missing".to_string(), "missing"),
+        Cell::Text(text) => (text.clone(), "value"),
+    }
+}
+
+fn render_markdown(
+    tables: &[Table],
+    charts: &[Chart],
+    provers: &[String],
+    baseline: &str,
+) -> String {
+    let mut out = String::from("# Benchmark report\n\n");
+    out += &format!("Ratios are relative to {baseline}.\n");
+    if !charts.is_empty() {
+        out += "\n## Charts\n";
+    }
+    for chart in charts {
+        out += &format!("\n![{}]({})\n", chart.title, chart.file_name);
+    }
+    for table in tables {
+        out += &format!("\n## {}\n\n", table.title.replace('|', "\\|"));
+        out += &format!("| | {} |\n", provers.join(" | "));
+        out += &format!("|---|{}\n", "---:|".repeat(provers.len()));
+        for (label, unit, cells) in &table.rows {
+            let cells: Vec<String> = cells
+                .iter()
+                .map(|cell| match format_cell(cell, *unit) {
+                    (text, "value") => text,
+                    (text, _) => format!("**{text}**"),
+                })
+                .collect();
+            out += &format!("| {label} | {} |\n", cells.join(" | "));
+        }
+    }
+    out
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+fn render_html(tables: &[Table], charts: &[Chart], provers: &[String], baseline: &str) -> String {
+    let mut out = String::from(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Benchmark report</title>\n\
+         <style>\n\
+         body { font-family: sans-serif; margin: 2em; }\n\
+         table { border-collapse: collapse; margin-bottom: 2em; }\n\
+         th, td { border: 1px solid #ccc; padding: 0.3em 0.8em; text-align: right; }\n\
+         th:first-child, td:first-child { text-align: left; }\n\
+         td.failed { background: #f8d0d0; font-weight: bold; }\n\
+         td.missing { background: #f4e8b0; font-weight: bold; }\n\
+         td.unsupported { background: #e4e4e4; font-weight: bold; }\n\
+         </style>\n</head>\n<body>\n<h1>Benchmark report</h1>\n",
+    );
+    out += &format!("<p>Ratios are relative to {}.</p>\n", escape_html(baseline));
+    // the charts are inlined to keep the page self-contained
+    if !charts.is_empty() {
+        out += "<h2>Charts</h2>\n";
+    }
+    for chart in charts {
+        out += &format!("<figure>\n{}</figure>\n", chart.svg);
+    }
+    for table in tables {
+        out += &format!("<h2>{}</h2>\n<table>\n<tr><th></th>", escape_html(&table.title));
+        for prover in provers {
+            out += &format!("<th>{}</th>", escape_html(prover));
+        }
+        out += "</tr>\n";
+        for (label, unit, cells) in &table.rows {
+            out += &format!("<tr><td>{label}</td>");
+            for cell in cells {
+                let (text, class) = format_cell(cell, *unit);
+                out += &format!("<td class=\"{class}\">{}</td>", escape_html(&text));
+            }
+            out += "</tr>\n";
+        }
+        out += "</table>\n";
+    }
+    out += "</body>\n</html>\n";
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::results::{
+        testing::{ended, row},
+        RunStatus,
+    };
+
+    fn cells<'a>(table: &'a Table, label: &str) -> &'a [Cell] {
+        &table.rows.iter().find(|(l, _, _)| *l == label).unwrap().2
+    }
+
+    #[test]
+    fn groups_by_program_input_and_configuration() {
+        let results = vec![
+            row("sp1", 20, &[("core_prove_duration", 1.0)]),
+            row("risc0", 20, &[("core_prove_duration", 2.0)]),
+            row("sp1", 21, &[("core_prove_duration", 1.0)]),
+            ResultRow { program_args: "100".to_string(), ..row("sp1", 20, &[]) },
+            ResultRow { hashfn: "blake3".to_string(), ..row("risc0", 20, &[]) },
+        ];
+        let provers = provers(&results);
+        assert_eq!(provers, ["sp1", "risc0"]);
+        let titles: Vec<String> =
+            tables(&results, &provers, "sp1").into_iter().map(|table| table.title).collect();
+        assert_eq!(
+            titles,
+            [
+                "fibonacci (blake3, shard size 20)",
+                "fibonacci (poseidon, shard size 20)",
+                "fibonacci (poseidon, shard size 21)",
+                "fibonacci 100 (poseidon, shard size 20)",
+            ]
+        );
+    }
+
+    #[test]
+    fn cells_compare_to_the_baseline() {
+        let results = vec![
+            row("sp1", 20, &[("core_prove_duration", 2.0)]),
+            row("sp1", 20, &[("core_prove_duration", 4.0)]),
+            row("risc0", 20, &[("core_prove_duration", 6.0)]),
+            // failed trials are left out of the mean
+            ResultRow {
+                status: RunStatus::Failed,
+                ..row("risc0", 20, &[("core_prove_duration", 100.0)])
+            },
+            ended("powdr", 20, RunStatus::Failed),
+            ended("jolt", 20, RunStatus::Unsupported),
+            ResultRow { shard_size: 21, ..row("nexus", 20, &[("core_prove_duration", 1.0)]) },
+        ];
+        let provers = provers(&results);
+        let tables = tables(&results, &provers, "sp1");
+        assert_eq!(tables.len(), 2);
+        let cells = cells(&tables[0], "core proving");
+        assert!(matches!(cells[0], Cell::Value(value, None) if value == 3.0));
+        assert!(
+            matches!(cells[1], Cell::Value(value, Some(ratio)) if value == 6.0 && ratio == 2.0)
+        );
+        assert!(matches!(cells[2], Cell::Failed));
+        assert!(matches!(cells[3], Cell::Unsupported));
+        assert!(matches!(cells[4], Cell::Missing));
+    }
+
+    #[test]
+    fn untracked_cycle_regions_are_unsupported() {
+        let regions = |prover, regions: &str| ResultRow {
+            cycle_regions: regions.to_string(),
+            ..row(prover, 20, &[("core_prove_duration", 1.0)])
+        };
+        let results = vec![
+            regions("sp1", r#"{"execute":100}"#),
+            regions("powdr-plonky3", "unsupported"),
+            regions("risc0", "{}"),
+        ];
+        let provers = provers(&results);
+        let tables = tables(&results, &provers, "sp1");
+        let cells = cells(&tables[0], "cycle regions");
+        assert!(matches!(&cells[0], Cell::Text(text) if text.contains("execute")));
+        assert!(matches!(cells[1], Cell::Unsupported));
+        // a guest without regions has none to show
+        assert!(matches!(cells[2], Cell::Missing));
+    }
+
+    #[test]
+    fn unreported_metrics_are_left_out() {
+        let results = vec![row("sp1", 20, &[("core_prove_duration", 1.0)])];
+        let tables = tables(&results, &provers(&results), "sp1");
+        let labels: Vec<&str> = tables[0].rows.iter().map(|(label, _, _)| *label).collect();
+        assert_eq!(labels, ["core proving"]);
+    }
+
+    #[test]
+    fn failures_are_highlighted() {
+        let results = vec![
+            row("sp1", 20, &[("core_prove_duration", 1.0)]),
+            ended("risc0", 20, RunStatus::Failed),
+        ];
+        let provers = provers(&results);
+        let markdown = render_markdown(&tables(&results, &provers, "sp1"), &[], &provers, "sp1");
+        assert!(markdown.contains("| core proving | 1.00 s | **failed** |"), "{markdown}");
+        let html = render_html(&tables(&results, &provers, "sp1"), &[], &provers, "sp1");
+        assert!(html.contains("<td class=\"failed\">failed</td>"), "{html}");
+    }
+
+    #[test]
+    fn charts_per_program() {
+        let sizes = |shard_size, memory| {
+            let metrics =
+                [("core_prove_duration", 1.0), ("core_proof_size", 1e6), ("peak_memory", memory)];
+            row("sp1", shard_size, &metrics)
+        };
+        let results = vec![
+            sizes(20, 4e9),
+            sizes(21, 6e9),
+            ResultRow { program: "sha256".to_string(), ..sizes(20, 4e9) },
+        ];
+        let charts = charts(&results, &provers(&results), "report");
+        let file_names: Vec<&str> = charts.iter().map(|chart| chart.file_name.as_str()).collect();
+        assert_eq!(
+            file_names,
+            [
+                "report_throughput.svg",
+                "report_prove_time_fibonacci_poseidon.svg",
+                "report_memory_fibonacci_poseidon.svg",
+                "report_core_proof_size.svg"
+            ]
+        );
+        // a group of bars per program
+        let proof_size = &charts[3].svg;
+        assert!(proof_size.contains(">fibonacci</text>") && proof_size.contains(">sha256</text>"));
+    }
+}
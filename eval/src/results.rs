@@ -0,0 +1,247 @@
+//! Reading the benchmark results files written by `run_benchmark`.
+
+use std::{collections::HashMap, fmt, path::Path, str::FromStr};
+
+use csv::ReaderBuilder;
+use serde::Serialize;
+
+/// How a run ended, as written to the `status` column.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RunStatus {
+    /// The program was proven.
+    #[default]
+    Ok,
+    /// The prover panicked. The metrics of the run are left empty.
+    Failed,
+    /// The prover does not support the configuration, so nothing was run.
+    Unsupported,
+    /// The program was only executed, with `--execute-only`, so the proving metrics are empty.
+    Executed,
+}
+
+impl fmt::Display for RunStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            RunStatus::Ok => "ok",
+            RunStatus::Failed => "failed",
+            RunStatus::Unsupported => "unsupported",
+            RunStatus::Executed => "executed",
+        })
+    }
+}
+
+impl FromStr for RunStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<RunStatus, String> {
+        match s {
+            "ok" => Ok(RunStatus::Ok),
+            "failed" => Ok(RunStatus::Failed),
+            "unsupported" => Ok(RunStatus::Unsupported),
+            "executed" => Ok(RunStatus::Executed),
+            _ => Err(format!("Unknown run status {s}")),
+        }
+    }
+}
+
+/// A row of a results file.
+#[derive(Clone)]
+pub struct ResultRow {
+    pub program: String,
+    pub program_args: String,
+    pub prover: String,
+    pub hashfn: String,
+    pub shard_size: u64,
+    /// The configuration of the prover as JSON, empty in older results files.
+    pub prover_config: String,
+    /// The statistics specific to the prover as JSON, empty if it reports none.
+    pub prover_stats: String,
+    /// The cycles of each cycle region of the guest as JSON, empty in older results files.
+    pub cycle_regions: String,
+    /// The duration of each phase of the run as JSON, empty if the prover reports none.
+    pub phases: String,
+    /// How the run ended. Older results files have no status column, and only hold the runs that
+    /// were proven.
+    pub status: RunStatus,
+    /// The numeric columns, by column name. Older results files may lack some of them.
+    pub metrics: HashMap<String, f64>,
+}
+
+impl ResultRow {
+    pub fn metric(&self, name: &str) -> Option<f64> {
+        self.metrics.get(name).copied()
+    }
+
+    /// Whether the prover panicked.
+    pub fn failed(&self) -> bool {
+        self.status == RunStatus::Failed
+    }
+
+    /// Whether the prover does not support the configuration, so nothing was run.
+    pub fn unsupported(&self) -> bool {
+        self.status == RunStatus::Unsupported
+    }
+
+    /// The configuration that runs are matched on across result sets.
+    pub fn key(&self) -> RunKey {
+        RunKey {
+            program: self.program.clone(),
+            program_args: self.program_args.clone(),
+            prover: self.prover.clone(),
+            hashfn: self.hashfn.clone(),
+            shard_size: self.shard_size,
+        }
+    }
+}
+
+/// The configuration of a run, without its measurements.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RunKey {
+    pub program: String,
+    pub program_args: String,
+    pub prover: String,
+    pub hashfn: String,
+    pub shard_size: u64,
+}
+
+impl std::fmt::Display for RunKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.program)?;
+        if !self.program_args.is_empty() {
+            write!(f, " {}", self.program_args)?;
+        }
+        write!(f, " {} {} {}", self.prover, self.hashfn, self.shard_size)
+    }
+}
+
+/// Reads all rows of a results file.
+pub fn read_results(path: &Path) -> Vec<ResultRow> {
+    let mut reader = ReaderBuilder::new()
+        .from_path(path)
+        .unwrap_or_else(|_| panic!("Failed to open results file: {:?}", path));
+    let headers = reader.headers().expect("Results file has no header").clone();
+    reader
+        .records()
+        .map(|record| {
+            let record = record.expect("Invalid results row");
+            let optional_column = |name: &str| {
+                let i = headers.iter().position(|header| header == name)?;
+                record.get(i).map(|value| value.to_string())
+            };
+            let column = |name: &str| {
+                optional_column(name)
+                    .unwrap_or_else(|| panic!("Results file {:?} has no {} column", path, name))
+            };
+            let metrics = headers
+                .iter()
+                .zip(record.iter())
+                .filter_map(|(name, value)| Some((name.to_string(), value.parse().ok()?)))
+                .collect();
+            ResultRow {
+                program: column("program"),
+                program_args: column("program_args"),
+                prover: column("prover"),
+                hashfn: column("hashfn"),
+                shard_size: column("shard_size").parse().expect("Invalid shard size"),
+                prover_config: optional_column("prover_config").unwrap_or_default(),
+                prover_stats: optional_column("prover_stats").unwrap_or_default(),
+                cycle_regions: optional_column("cycle_regions").unwrap_or_default(),
+                phases: optional_column("phases").unwrap_or_default(),
+                status: optional_column("status")
+                    .map(|status| {
+                        status.parse().unwrap_or_else(|err| panic!("{:?}: {}", path, err))
+                    })
+                    .unwrap_or_default(),
+                metrics,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+pub mod testing {
+    use super::*;
+
+    /// A run of `fibonacci` with the given prover, shard size and metrics.
+    pub fn row(prover: &str, shard_size: u64, metrics: &[(&str, f64)]) -> ResultRow {
+        ResultRow {
+            program: "fibonacci".to_string(),
+            program_args: String::new(),
+            prover: prover.to_string(),
+            hashfn: "poseidon".to_string(),
+            shard_size,
+            prover_config: String::new(),
+            prover_stats: String::new(),
+            cycle_regions: String::new(),
+            phases: String::new(),
+            status: RunStatus::Ok,
+            metrics: metrics.iter().map(|&(name, value)| (name.to_string(), value)).collect(),
+        }
+    }
+
+    /// A run of `fibonacci` with the given prover and shard size that ended with `status`.
+    pub fn ended(prover: &str, shard_size: u64, status: RunStatus) -> ResultRow {
+        ResultRow { status, ..row(prover, shard_size, &[]) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    fn read(name: &str, csv: &str) -> Vec<ResultRow> {
+        let path = std::env::temp_dir().join(format!("results_{}_{name}.csv", std::process::id()));
+        fs::write(&path, csv).unwrap();
+        let rows = read_results(&path);
+        fs::remove_file(&path).unwrap();
+        rows
+    }
+
+    #[test]
+    fn reads_the_status() {
+        let rows = read(
+            "status",
+            "program,program_args,prover,hashfn,shard_size,cycles,core_prove_duration,status\n\
+             fibonacci,,sp1,poseidon,20,1000,0,ok\n\
+             fibonacci,,sp1,poseidon,21,,,failed\n\
+             fibonacci,,sp1,blake3,20,,,unsupported\n\
+             fibonacci,,sp1,poseidon,22,1000,,executed\n",
+        );
+        let statuses: Vec<RunStatus> = rows.iter().map(|row| row.status).collect();
+        assert_eq!(
+            statuses,
+            [RunStatus::Ok, RunStatus::Failed, RunStatus::Unsupported, RunStatus::Executed]
+        );
+        // a run is failed because of its status, not because of its metrics
+        assert!(!rows[0].failed());
+        assert_eq!(rows[0].metric("core_prove_duration"), Some(0.0));
+        assert!(rows[1].failed() && !rows[1].unsupported());
+        assert!(rows[2].unsupported() && !rows[2].failed());
+        // the metrics a run did not get to are empty
+        assert_eq!(rows[3].metric("cycles"), Some(1000.0));
+        assert_eq!(rows[3].metric("core_prove_duration"), None);
+    }
+
+    #[test]
+    fn runs_without_a_status_were_proven() {
+        let rows = read(
+            "legacy",
+            "program,program_args,prover,hashfn,shard_size,core_prove_duration\n\
+             fibonacci,,sp1,poseidon,20,1.5\n",
+        );
+        assert_eq!(rows[0].status, RunStatus::Ok);
+    }
+
+    #[test]
+    fn status_roundtrip() {
+        for status in
+            [RunStatus::Ok, RunStatus::Failed, RunStatus::Unsupported, RunStatus::Executed]
+        {
+            assert_eq!(status.to_string().parse::<RunStatus>(), Ok(status));
+        }
+        assert!("crashed".parse::<RunStatus>().is_err());
+    }
+}
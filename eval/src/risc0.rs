@@ -1,68 +1,184 @@
-use std::fs;
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::Write,
+    path::Path,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 use risc0_zkvm::{
-    compute_image_id, get_prover_server, ExecutorEnv, ExecutorImpl, ProverOpts, VerifierContext,
+    compute_image_id, get_prover_server, sha::Digest, ExecutorEnv, ExecutorImpl, InnerReceipt,
+    ProverOpts, Receipt, ReceiptKind, VerifierContext,
 };
 
-use crate::{utils::*, EvalArgs, HashFnId, PerformanceReport, ProgramId};
+use crate::{
+    brainfuck::{check_brainfuck_output, get_brainfuck_input},
+    config::{parse_opt_value, prover_opts, ProverConfig, Risc0Config},
+    proofs::{read_proof_file, save_proofs, Manifest},
+    stats::{ProverStats, Risc0Stats},
+    utils::*,
+    EvalArgs, HashFnId, PerformanceReport, ProgramId,
+};
 
 pub struct Risc0Evaluator;
 
-fn build_env(args: &EvalArgs) -> ExecutorEnv {
+/// A writer appending to a buffer shared with the evaluator, which captures the guest's stderr.
+#[derive(Clone, Default)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+fn build_env(args: &EvalArgs, config: &Risc0Config, stderr: SharedBuffer) -> ExecutorEnv<'static> {
+    let mut builder = ExecutorEnv::builder();
+    builder.segment_limit_po2(config.segment_limit_po2).stderr(stderr);
     match args.program {
         ProgramId::Brainfuck => {
-            let input = get_brainfuck_input(args);
-            ExecutorEnv::builder()
-                .segment_limit_po2(args.shard_size as u32)
-                .write(&input)
-                .expect("Failed to write input to executor")
-                .build()
-                .unwrap()
+            let (program, input) = get_brainfuck_input(args);
+            let input = (program.encode(), input);
+            builder.write(&input).expect("Failed to write input to executor");
+        }
+        ProgramId::SSZWithdrawals => {
+            let input = get_ssz_withdrawals_input(args);
+            builder.write(&input).expect("Failed to write input to executor");
         }
         ProgramId::Reth => {
             let input = get_reth_input(args);
-            ExecutorEnv::builder()
-                .segment_limit_po2(args.shard_size as u32)
-                .write(&input)
-                .expect("Failed to write input to executor")
-                .build()
-                .unwrap()
+            builder.write(&input).expect("Failed to write input to executor");
         }
-        ProgramId::BrainfuckAsm | ProgramId::BrainfuckCompiler => {
-            panic!("{} is a powdr only benchmark", args.program.to_string())
+        ProgramId::BrainfuckAsm |
+        ProgramId::BrainfuckCompiler |
+        ProgramId::BrainfuckCompilerOpt => {
+            panic!("{} is a powdr only benchmark", args.program)
         }
-        _ => ExecutorEnv::builder().segment_limit_po2(args.shard_size as u32).build().unwrap(),
+        _ => {}
     }
+    builder.build().unwrap()
 }
 
-impl Risc0Evaluator {
-    pub fn eval(args: &EvalArgs) -> PerformanceReport {
-        if args.hashfn != HashFnId::Poseidon {
-            panic!("Only Poseidon hash function is supported for Risc0.");
+/// Sums the cycles of the `cycle-region: <name> <cycles>` lines the guest wrote to stderr, and
+/// passes the other lines through.
+fn cycle_regions(stderr: &[u8]) -> BTreeMap<String, u64> {
+    let mut regions = BTreeMap::new();
+    for line in String::from_utf8_lossy(stderr).lines() {
+        let region = line
+            .strip_prefix("cycle-region: ")
+            .and_then(|region| region.rsplit_once(' '))
+            .and_then(|(name, cycles)| Some((name, cycles.parse::<u64>().ok()?)));
+        match region {
+            Some((name, cycles)) => *regions.entry(name.to_string()).or_default() += cycles,
+            None => eprintln!("{line}"),
         }
+    }
+    regions
+}
+
+/// The name of a hash function in RISC0's prover options, where Poseidon is Poseidon2.
+fn risc0_hashfn(hashfn: &HashFnId) -> &'static str {
+    match hashfn {
+        HashFnId::Sha256 => "sha-256",
+        HashFnId::Poseidon => "poseidon2",
+        HashFnId::Blake2b => "blake2b",
+        _ => unreachable!("{} is not supported by RISC0", hashfn),
+    }
+}
+
+/// Builds the prover options from the defaults, the hash function and the `key=value` overrides.
+/// The receipt kind is the kind the core proof is compressed into, succinct by default, and
+/// `composite` skips the compression. Returns the options to prove the session with, which keep
+/// the receipt composite so that the core proof is timed on its own, the options to compress it
+/// with, and the effective value of every option, to be recorded with the results.
+fn build_prover_opts(
+    hashfn: &HashFnId,
+    overrides: &[(String, String)],
+) -> (ProverOpts, ProverOpts, BTreeMap<String, String>) {
+    let mut opts = ProverOpts::succinct().with_hashfn(risc0_hashfn(hashfn).to_string());
+    for (key, value) in overrides {
+        opts = match key.as_str() {
+            "hashfn" => panic!("Set the hash function with --hashfn instead of {key}"),
+            "receipt_kind" => opts.with_receipt_kind(match value.as_str() {
+                "composite" => ReceiptKind::Composite,
+                "succinct" => ReceiptKind::Succinct,
+                "groth16" => ReceiptKind::Groth16,
+                _ => {
+                    panic!("Unknown receipt kind {value}, expected composite, succinct or groth16")
+                }
+            }),
+            "prove_guest_errors" => opts.with_prove_guest_errors(parse_opt_value(key, value)),
+            "max_segment_po2" => opts.with_max_segment_po2(parse_opt_value(key, value)),
+            _ => panic!(
+                "Unknown RISC0 prover option {key}, expected receipt_kind, prove_guest_errors \
+                 or max_segment_po2"
+            ),
+        };
+    }
 
+    let effective = BTreeMap::from([
+        ("hashfn".to_string(), opts.hashfn.clone()),
+        ("receipt_kind".to_string(), format!("{:?}", opts.receipt_kind).to_lowercase()),
+        ("prove_guest_errors".to_string(), opts.prove_guest_errors.to_string()),
+        ("max_segment_po2".to_string(), opts.max_segment_po2.to_string()),
+    ]);
+    let prove_opts = opts.clone().with_receipt_kind(ReceiptKind::Composite);
+    (prove_opts, opts, effective)
+}
+
+impl Risc0Evaluator {
+    pub fn eval(args: &EvalArgs) -> PerformanceReport {
         let elf_path = get_elf(args);
         let elf = fs::read(&elf_path).unwrap();
         let image_id = compute_image_id(elf.as_slice()).unwrap();
 
         // set program inputs
-        let env = build_env(args);
+        let mut config = Risc0Config::new(args);
+        let stderr = SharedBuffer::default();
+        let env = build_env(args, &config, stderr.clone());
+
+        let (prove_opts, compress_opts, effective_opts) =
+            build_prover_opts(&args.hashfn, &prover_opts(args));
+        config.prover_opts = effective_opts;
 
-        // Compute some statistics.
+        // Generate the session, which is both timed and proven.
         let mut exec = ExecutorImpl::from_elf(env, &elf).unwrap();
-        let session = exec.run().unwrap();
+        let (session, execution_duration) = time_operation(|| exec.run().unwrap());
         let cycles = session.user_cycles;
+        let cycle_regions = Some(cycle_regions(&stderr.0.lock().unwrap()));
+        let stats = Risc0Stats {
+            total_cycles: session.total_cycles,
+            user_cycles: session.user_cycles,
+            paging_cycles: session.paging_cycles,
+            reserved_cycles: session.reserved_cycles,
+            segments: session.segments.len(),
+            segment_po2: session
+                .segments
+                .iter()
+                .map(|segment| segment.resolve().unwrap().po2 as u32)
+                .collect(),
+        };
 
-        // Setup the prover.
-        let start = std::time::Instant::now();
-        let env = build_env(args);
-        let opts = ProverOpts::default();
-        let prover = get_prover_server(&opts).unwrap();
+        if args.execute_only {
+            return PerformanceReport {
+                shards: session.segments.len(),
+                cycles: cycles as u64,
+                execution_duration: execution_duration.as_secs_f64(),
+                cycle_regions,
+                prover_config: Some(ProverConfig::Risc0(config)),
+                prover_stats: Some(ProverStats::Risc0(stats)),
+                ..Default::default()
+            };
+        }
 
-        // Generate the session.
-        let mut exec = ExecutorImpl::from_elf(env, &elf).unwrap();
-        let setup_duration = start.elapsed();
-        let (session, execution_duration) = time_operation(|| exec.run().unwrap());
+        // Setup the prover.
+        let (prover, setup_duration) = time_operation(|| get_prover_server(&prove_opts).unwrap());
 
         // Generate the proof.
         let ctx = VerifierContext::default();
@@ -71,6 +187,10 @@ impl Risc0Evaluator {
 
         let receipt = info.receipt;
 
+        if args.program == ProgramId::Brainfuck {
+            check_brainfuck_output(args, &receipt.journal.bytes);
+        }
+
         let composite_receipt = receipt.inner.composite().unwrap();
         let num_segments = composite_receipt.segments.len();
 
@@ -85,34 +205,115 @@ impl Risc0Evaluator {
 
         // Now compress the proof with recursion.
         // let composite_receipt = receipt.inner.composite().unwrap();
-        let (compressed_proof, compress_duration) =
-            time_operation(|| prover.compress(&ProverOpts::succinct(), &receipt).unwrap());
+        let compress =
+            !args.core_only && !matches!(compress_opts.receipt_kind, ReceiptKind::Composite);
+        let compressed = compress.then(|| {
+            let (compressed_proof, compress_duration) =
+                time_operation(|| prover.compress(&compress_opts, &receipt).unwrap());
 
-        // Verify the recursive proof
-        let ((), recursive_verify_duration) =
-            time_operation(|| compressed_proof.verify(image_id).unwrap());
+            // Verify the recursive proof
+            let ((), recursive_verify_duration) =
+                time_operation(|| compressed_proof.verify(image_id).unwrap());
 
-        let succinct_receipt = compressed_proof.inner.succinct().unwrap();
+            // Get the recursive proof size.
+            let recursive_proof_size = match &compressed_proof.inner {
+                InnerReceipt::Succinct(receipt) => receipt.seal.len() * 4,
+                InnerReceipt::Groth16(receipt) => receipt.seal.len(),
+                _ => unreachable!("unexpected receipt kind after compression"),
+            };
+            let compressed_bytes = bincode::serialize(&compressed_proof).unwrap();
+            (compress_duration, recursive_verify_duration, recursive_proof_size, compressed_bytes)
+        });
+        let (compress_duration, recursive_verify_duration, recursive_proof_size, compressed_bytes) =
+            compressed.unwrap_or_default();
 
-        // Get the recursive proof size.
-        let recursive_proof_size = succinct_receipt.seal.len() * 4;
-        let prove_duration = core_prove_duration + compress_duration;
+        let core_bytes = bincode::serialize(&receipt).unwrap();
+        let image_id_bytes = bincode::serialize(&image_id).unwrap();
+        let mut files = vec![
+            ("core_receipt.bin", core_bytes.as_slice()),
+            ("image_id.bin", image_id_bytes.as_slice()),
+            ("journal.bin", receipt.journal.bytes.as_slice()),
+        ];
+        if compress {
+            files.push(("compressed_receipt.bin", compressed_bytes.as_slice()));
+        }
+        save_proofs(args, &files);
 
         // Create the performance report.
         PerformanceReport {
             shards: num_segments,
             cycles: cycles as u64,
-            speed: (cycles as f64) / prove_duration.as_secs_f64(),
             execution_duration: execution_duration.as_secs_f64(),
-            prove_duration: prove_duration.as_secs_f64(),
             core_prove_duration: core_prove_duration.as_secs_f64(),
             core_verify_duration: core_verify_duration.as_secs_f64(),
             core_proof_size,
-            core_speed: (cycles as f64) / core_prove_duration.as_secs_f64(),
             compress_prove_duration: compress_duration.as_secs_f64(),
             compress_verify_duration: recursive_verify_duration.as_secs_f64(),
             compress_proof_size: recursive_proof_size,
             setup_duration: setup_duration.as_secs_f64(),
+            cycle_regions,
+            prover_config: Some(ProverConfig::Risc0(config)),
+            prover_stats: Some(ProverStats::Risc0(stats)),
+            ..Default::default()
+        }
+    }
+}
+
+/// Verifies the receipts saved by [`Risc0Evaluator::eval`] with `--save-proofs`. The verifier needs
+/// no setup, so there is no setup step.
+pub fn verify_proofs(dir: &Path, manifest: &Manifest) -> Vec<(String, Duration)> {
+    let image_id: Digest = bincode::deserialize(&read_proof_file(dir, "image_id.bin")).unwrap();
+    let journal = read_proof_file(dir, "journal.bin");
+
+    let mut durations = vec![];
+    for (file, step) in
+        [("core_receipt.bin", "core receipt"), ("compressed_receipt.bin", "compressed receipt")]
+    {
+        if !manifest.files.iter().any(|name| name == file) {
+            continue;
+        }
+        let receipt: Receipt = bincode::deserialize(&read_proof_file(dir, file)).unwrap();
+        assert_eq!(receipt.journal.bytes, journal, "the journal does not match {file}");
+        let (_, verify_duration) = time_operation(|| {
+            receipt
+                .verify(image_id)
+                .unwrap_or_else(|err| panic!("{step} verification failed: {err}"))
+        });
+        durations.push((step.to_string(), verify_duration));
+    }
+    durations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn opts(overrides: &[(&str, &str)]) -> (ProverOpts, ProverOpts, BTreeMap<String, String>) {
+        let overrides: Vec<_> =
+            overrides.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect();
+        build_prover_opts(&HashFnId::Sha256, &overrides)
+    }
+
+    #[test]
+    fn sessions_are_proven_into_composite_receipts() {
+        let (prove_opts, compress_opts, effective) = opts(&[]);
+        assert!(matches!(prove_opts.receipt_kind, ReceiptKind::Composite));
+        assert!(matches!(compress_opts.receipt_kind, ReceiptKind::Succinct));
+        assert_eq!(effective["receipt_kind"], "succinct");
+
+        let (prove_opts, compress_opts, effective) = opts(&[
+            ("receipt_kind", "groth16"),
+            ("prove_guest_errors", "true"),
+            ("max_segment_po2", "20"),
+        ]);
+        assert!(matches!(prove_opts.receipt_kind, ReceiptKind::Composite));
+        assert!(matches!(compress_opts.receipt_kind, ReceiptKind::Groth16));
+        assert_eq!(effective["receipt_kind"], "groth16");
+        // everything but the receipt kind is the same for both
+        for opts in [&prove_opts, &compress_opts] {
+            assert_eq!(opts.hashfn, "sha-256");
+            assert!(opts.prove_guest_errors);
+            assert_eq!(opts.max_segment_po2, 20);
         }
     }
 }
@@ -1,27 +1,149 @@
-use std::fs;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::Path,
+    time::Duration,
+};
 
-use crate::{utils::*, EvalArgs, PerformanceReport, ProgramId};
+use crate::{
+    brainfuck::{check_brainfuck_output, get_brainfuck_input},
+    cache::cached_setup,
+    config::{parse_opt_value, prover_opts, ProverConfig, Sp1Config},
+    proofs::{read_proof_file, save_proofs, Manifest},
+    stats::{ProverStats, Sp1Stats},
+    utils::*,
+    EvalArgs, PerformanceReport, ProgramId,
+};
 
-use sp1_prover::components::DefaultProverComponents;
-use sp1_sdk::{utils, SP1Context, SP1Prover, SP1Stdin};
+use sp1_prover::{
+    components::DefaultProverComponents, InnerSC, SP1CoreProof, SP1ReduceProof, SP1VerifyingKey,
+    SP1_CIRCUIT_VERSION,
+};
+use sp1_sdk::{utils, ExecutionReport, SP1Context, SP1Prover, SP1ProverOpts, SP1Stdin};
 
 pub struct SP1Evaluator;
 
+/// Collects the cycles of the cycle regions of the guest, which are its cycle tracker regions.
+fn cycle_regions(cycle_tracker: &HashMap<String, u64>) -> BTreeMap<String, u64> {
+    cycle_tracker.iter().map(|(name, cycles)| (name.clone(), *cycles)).collect()
+}
+
+/// Collects the number of times each opcode and syscall was executed, leaving out those that never
+/// were.
+fn histograms(report: &ExecutionReport) -> Vec<(String, String, u64)> {
+    let opcodes = report
+        .opcode_counts
+        .iter()
+        .filter(|(_, count)| **count > 0)
+        .map(|(opcode, count)| ("opcode".to_string(), opcode.to_string(), *count));
+    let syscalls =
+        report.syscall_counts.iter().filter(|(_, count)| **count > 0).map(|(code, count)| {
+            ("syscall".to_string(), format!("{code:?}").to_lowercase(), *count)
+        });
+    opcodes.chain(syscalls).collect()
+}
+
+/// Summarises the syscalls of an execution. The precompiles are the syscalls whose events are
+/// sent to their own chips.
+fn stats(report: &ExecutionReport) -> Sp1Stats {
+    let instructions = report.total_instruction_count();
+    let syscalls = report.syscall_counts.values().sum();
+    let precompiles: BTreeMap<String, u64> = report
+        .syscall_counts
+        .iter()
+        .filter(|(code, count)| code.should_send() == 1 && **count > 0)
+        .map(|(code, count)| (format!("{code:?}").to_lowercase(), *count))
+        .collect();
+    let precompile_calls = precompiles.values().sum();
+    Sp1Stats {
+        instructions,
+        syscalls,
+        precompile_calls,
+        precompile_call_ratio: (instructions > 0)
+            .then(|| precompile_calls as f64 / instructions as f64),
+        precompiles,
+    }
+}
+
+/// Builds the prover options from the defaults and the `key=value` overrides, where the key is
+/// a field of the core or recursion options, e.g. `core.shard_batch_size`. Returns them with the
+/// effective value of every option, to be recorded with the results.
+fn build_prover_opts(overrides: &[(String, String)]) -> (SP1ProverOpts, BTreeMap<String, String>) {
+    let mut opts = SP1ProverOpts::default();
+    for (key, value) in overrides {
+        // not a field of the options, but an environment variable set by `Sp1Config`
+        if key == "shard_chunking_multiplier" {
+            continue;
+        }
+        let stage_opts = match key.split_once('.') {
+            Some(("core", _)) => &mut opts.core_opts,
+            Some(("recursion", _)) => &mut opts.recursion_opts,
+            _ => panic!(
+                "SP1 prover options start with core. or recursion., or are \
+                 shard_chunking_multiplier, got {key}"
+            ),
+        };
+        match key.split_once('.').unwrap().1 {
+            "shard_batch_size" => stage_opts.shard_batch_size = parse_opt_value(key, value),
+            "reconstruct_commitments" => {
+                stage_opts.reconstruct_commitments = parse_opt_value(key, value)
+            }
+            "checkpoints_channel_capacity" => {
+                stage_opts.checkpoints_channel_capacity = parse_opt_value(key, value)
+            }
+            "records_and_traces_channel_capacity" => {
+                stage_opts.records_and_traces_channel_capacity = parse_opt_value(key, value)
+            }
+            "shard_size" => panic!("Set the shard size with --shard-size instead of {key}"),
+            _ => panic!(
+                "Unknown SP1 prover option {key}, expected shard_batch_size, \
+                 reconstruct_commitments, checkpoints_channel_capacity or \
+                 records_and_traces_channel_capacity"
+            ),
+        }
+    }
+
+    let mut effective = BTreeMap::new();
+    for (stage, stage_opts) in [("core", &opts.core_opts), ("recursion", &opts.recursion_opts)] {
+        let mut record = |name: &str, value: String| {
+            effective.insert(format!("{stage}.{name}"), value);
+        };
+        record("shard_size", stage_opts.shard_size.to_string());
+        record("shard_batch_size", stage_opts.shard_batch_size.to_string());
+        record("reconstruct_commitments", stage_opts.reconstruct_commitments.to_string());
+        record("checkpoints_channel_capacity", stage_opts.checkpoints_channel_capacity.to_string());
+        record(
+            "records_and_traces_channel_capacity",
+            stage_opts.records_and_traces_channel_capacity.to_string(),
+        );
+    }
+    (opts, effective)
+}
+
 impl SP1Evaluator {
     pub fn eval(args: &EvalArgs) -> PerformanceReport {
         // Setup the logger.
         utils::setup_logger();
 
         // Set enviroment variables to configure the prover.
-        std::env::set_var("SHARD_SIZE", format!("{}", 1 << args.shard_size));
-        if args.program == ProgramId::Reth {
-            std::env::set_var("SHARD_CHUNKING_MULTIPLIER", "4");
-        }
+        let mut config = Sp1Config::new(args);
+        config.apply();
+
+        // Setup the prover options, which read the environment variables for their defaults.
+        let (opts, effective_opts) = build_prover_opts(&prover_opts(args));
+        config.prover_opts = effective_opts;
 
         // set program inputs
         let stdin = match args.program {
             ProgramId::Brainfuck => {
-                let input = get_brainfuck_input(args);
+                let (program, input) = get_brainfuck_input(args);
+                let input = (program.encode(), input);
+                let mut stdin = SP1Stdin::new();
+                stdin.write(&input);
+                stdin
+            }
+            ProgramId::SSZWithdrawals => {
+                let input = get_ssz_withdrawals_input(args);
                 let mut stdin = SP1Stdin::new();
                 stdin.write(&input);
                 stdin
@@ -32,8 +154,10 @@ impl SP1Evaluator {
                 stdin.write(&input);
                 stdin
             }
-            ProgramId::BrainfuckAsm | ProgramId::BrainfuckCompiler => {
-                panic!("{} is a powdr only benchmark", args.program.to_string())
+            ProgramId::BrainfuckAsm |
+            ProgramId::BrainfuckCompiler |
+            ProgramId::BrainfuckCompilerOpt => {
+                panic!("{} is a powdr only benchmark", args.program)
             }
             _ => SP1Stdin::new(),
         };
@@ -44,18 +168,33 @@ impl SP1Evaluator {
 
         let prover = SP1Prover::<DefaultProverComponents>::new();
 
-        // Setup the program.
-        let ((pk, vk), setup_duration) = time_operation(|| prover.setup(&elf));
-
         // Execute the program.
         let context = SP1Context::default();
         let ((_, report), execution_duration) =
             time_operation(|| prover.execute(&elf, &stdin, context.clone()).unwrap());
 
         let cycles = report.total_instruction_count();
+        let cycle_regions = Some(cycle_regions(&report.cycle_tracker));
+        let histograms = histograms(&report);
+        let stats = stats(&report);
+
+        if args.execute_only {
+            // the shards are only known once proving, so estimate them from the shard size
+            return PerformanceReport {
+                shards: cycles.div_ceil(config.shard_size_cycles) as usize,
+                cycles: cycles as u64,
+                execution_duration: execution_duration.as_secs_f64(),
+                cycle_regions,
+                histograms,
+                prover_config: Some(ProverConfig::Sp1(config)),
+                prover_stats: Some(ProverStats::Sp1(stats)),
+                ..Default::default()
+            };
+        }
 
-        // Setup the prover opionts.
-        let opts = Default::default();
+        // Setup the program, or read its keys from the setup cache.
+        let ((pk, vk), setup) =
+            cached_setup(args, &elf, SP1_CIRCUIT_VERSION, || prover.setup(&elf));
 
         // Generate the core proof.
         let (core_proof, prove_core_duration) =
@@ -63,34 +202,88 @@ impl SP1Evaluator {
 
         let num_shards = core_proof.proof.0.len();
 
+        if args.program == ProgramId::Brainfuck {
+            check_brainfuck_output(args, core_proof.public_values.as_slice());
+        }
+
         // Verify the proof.
         let core_bytes = bincode::serialize(&core_proof).unwrap();
+        let public_values = core_proof.public_values.to_vec();
         let (_, verify_core_duration) = time_operation(|| {
             prover.verify(&core_proof.proof, &vk).expect("Proof verification failed")
         });
 
-        let (compress_proof, compress_duration) =
-            time_operation(|| prover.compress(&vk, core_proof, vec![], opts).unwrap());
-
-        let compress_bytes = bincode::serialize(&compress_proof).unwrap();
+        let (compress_bytes, compress_duration) = if args.core_only {
+            (vec![], Default::default())
+        } else {
+            let (compress_proof, compress_duration) =
+                time_operation(|| prover.compress(&vk, core_proof, vec![], opts).unwrap());
+            (bincode::serialize(&compress_proof).unwrap(), compress_duration)
+        };
 
-        let prove_duration = prove_core_duration + compress_duration;
+        let vk_bytes = bincode::serialize(&vk).unwrap();
+        let mut files = vec![
+            ("core_proof.bin", core_bytes.as_slice()),
+            ("vkey.bin", vk_bytes.as_slice()),
+            ("public_values.bin", public_values.as_slice()),
+        ];
+        if !args.core_only {
+            files.push(("compressed_proof.bin", compress_bytes.as_slice()));
+        }
+        save_proofs(args, &files);
 
         // Create the performance report.
         PerformanceReport {
             shards: num_shards,
             cycles: cycles as u64,
-            speed: (cycles as f64) / prove_core_duration.as_secs_f64(),
             execution_duration: execution_duration.as_secs_f64(),
-            prove_duration: prove_duration.as_secs_f64(),
             core_prove_duration: prove_core_duration.as_secs_f64(),
             core_verify_duration: verify_core_duration.as_secs_f64(),
             core_proof_size: core_bytes.len(),
-            core_speed: (cycles as f64) / prove_core_duration.as_secs_f64(),
             compress_prove_duration: compress_duration.as_secs_f64(),
             compress_verify_duration: 0.0, // TODO: fill this in.
             compress_proof_size: compress_bytes.len(),
-            setup_duration: setup_duration.as_secs_f64(),
+            setup_duration: setup.cold.as_secs_f64(),
+            warm_setup_duration: setup.warm.map(|d| d.as_secs_f64()),
+            setup_cache: setup.status,
+            cycle_regions,
+            histograms,
+            prover_config: Some(ProverConfig::Sp1(config)),
+            prover_stats: Some(ProverStats::Sp1(stats)),
+            ..Default::default()
         }
     }
 }
+
+/// Verifies the proofs saved by [`SP1Evaluator::eval`] with `--save-proofs`.
+pub fn verify_proofs(dir: &Path, manifest: &Manifest) -> Vec<(String, Duration)> {
+    utils::setup_logger();
+
+    let (prover, setup_duration) = time_operation(SP1Prover::<DefaultProverComponents>::new);
+    let vk: SP1VerifyingKey = bincode::deserialize(&read_proof_file(dir, "vkey.bin")).unwrap();
+    let mut durations = vec![("setup".to_string(), setup_duration)];
+
+    let core_proof: SP1CoreProof =
+        bincode::deserialize(&read_proof_file(dir, "core_proof.bin")).unwrap();
+    assert_eq!(
+        core_proof.public_values.as_slice(),
+        read_proof_file(dir, "public_values.bin"),
+        "the public values do not match the core proof"
+    );
+    let (_, core_verify_duration) = time_operation(|| {
+        prover.verify(&core_proof.proof, &vk).expect("Core proof verification failed")
+    });
+    durations.push(("core proof".to_string(), core_verify_duration));
+
+    if manifest.files.iter().any(|file| file == "compressed_proof.bin") {
+        let compressed_proof: SP1ReduceProof<InnerSC> =
+            bincode::deserialize(&read_proof_file(dir, "compressed_proof.bin")).unwrap();
+        let (_, compress_verify_duration) = time_operation(|| {
+            prover
+                .verify_compressed(&compressed_proof, &vk)
+                .expect("Compressed proof verification failed")
+        });
+        durations.push(("compressed proof".to_string(), compress_verify_duration));
+    }
+    durations
+}
@@ -0,0 +1,92 @@
+//! Statistics of a run that are specific to a prover, recorded with each result next to the
+//! metrics shared by all provers.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+/// The cycles of a RISC0 session. The segments are proven in `2^po2` cycles, which include paging
+/// and reserved cycles on top of the user cycles, so the proving cost follows the total cycles.
+#[derive(Debug, Clone, Serialize)]
+pub struct Risc0Stats {
+    /// The cycles of the session, summed over the segments, including padding to their po2.
+    pub total_cycles: u64,
+    /// The cycles spent executing the guest's instructions.
+    pub user_cycles: u64,
+    /// The cycles spent paging memory in and out.
+    pub paging_cycles: u64,
+    /// The cycles reserved for each segment's setup and teardown.
+    pub reserved_cycles: u64,
+    /// The number of segments.
+    pub segments: usize,
+    /// The po2 of each segment, in order.
+    pub segment_po2: Vec<u32>,
+}
+
+/// A summary of the opcodes and syscalls an SP1 execution ran, whose full histograms are written to
+/// `<filename>_<sha>_histograms.csv`.
+#[derive(Debug, Clone, Serialize)]
+pub struct Sp1Stats {
+    /// The number of executed instructions, the `cycles` of the run.
+    pub instructions: u64,
+    /// The number of syscalls.
+    pub syscalls: u64,
+    /// The number of calls to precompiles, such as SHA-256 or keccak, which are proven in their
+    /// own chips rather than as instructions.
+    pub precompile_calls: u64,
+    /// The precompile calls per executed instruction. This is a ratio of counts, not the share of
+    /// the cycles spent in precompiles, which SP1 does not report per syscall. Unknown if no
+    /// instruction was executed.
+    pub precompile_call_ratio: Option<f64>,
+    /// The calls to each precompile that was used.
+    pub precompiles: BTreeMap<String, u64>,
+}
+
+/// A chunk of a powdr execution with continuations. Every chunk starts with the bootloader, which
+/// loads the memory pages the chunk touches and checks them against the merkle root of the memory.
+#[derive(Debug, Clone, Serialize)]
+pub struct PowdrChunkStats {
+    /// The rows the chunk uses before its shutdown routine, bootloader included.
+    pub rows: u64,
+    /// The number of memory pages the bootloader loads.
+    pub pages: usize,
+    /// The rows the bootloader uses, read from the witness of the chunk, so unknown with
+    /// `--execute-only`.
+    pub bootloader_rows: Option<u64>,
+}
+
+/// A machine of a powdr program, i.e. a namespace of its PIL, such as `main`, `main_memory` or a
+/// co-processor. Under VADCOP each machine is proven in its own size.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PowdrMachineStats {
+    /// The number of witness columns.
+    pub witness_columns: usize,
+    /// The number of fixed columns.
+    pub fixed_columns: usize,
+    /// The rows of the witness, summed over the chunks of an execution with continuations.
+    pub rows: u64,
+}
+
+/// The statistics of a powdr run.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PowdrStats {
+    /// The chunks of an execution with continuations, empty without continuations.
+    pub chunks: Vec<PowdrChunkStats>,
+    /// The share of the rows of the chunks that the bootloaders use.
+    pub bootloader_share: Option<f64>,
+    /// The machines of the program, empty if no witness was generated.
+    pub machines: BTreeMap<String, PowdrMachineStats>,
+    /// The number of fixed columns of the optimized PIL.
+    pub fixed_columns: usize,
+    /// The number of identities of the optimized PIL.
+    pub identities: usize,
+}
+
+/// The statistics of the prover of a run, as recorded in the results.
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum ProverStats {
+    Sp1(Sp1Stats),
+    Risc0(Risc0Stats),
+    Powdr(PowdrStats),
+}
@@ -0,0 +1,219 @@
+//! The `tune` subcommand, searching the shard size with the fastest core proving.
+//!
+//! Every run is a separate process running this binary, so that the provers' global state such as
+//! SP1's `SHARD_SIZE` is fresh and the peak memory of each run can be measured on its own.
+
+use std::{
+    fs::{create_dir_all, File},
+    path::PathBuf,
+    process::Command,
+    thread,
+    time::Duration,
+};
+
+use clap::Args;
+
+use crate::{
+    config,
+    results::{read_results, ResultRow, RunStatus},
+    results_path,
+    utils::peak_memory,
+    BrainfuckCase, HashFnId, PowdrDegreeMode, PowdrLinker, ProgramId, ProverId,
+};
+
+/// The arguments of the `tune` subcommand.
+#[derive(Args, Clone)]
+pub struct TuneArgs {
+    #[arg(long)]
+    program: ProgramId,
+    #[arg(long)]
+    prover: ProverId,
+    #[arg(long)]
+    hashfn: HashFnId,
+    /// The smallest shard size to try, with the same meaning as `--shard-size`.
+    #[arg(long, default_value_t = 16)]
+    min_shard_size: u64,
+    /// The largest shard size to try.
+    #[arg(long, default_value_t = 22)]
+    max_shard_size: u64,
+    /// The memory available to the prover in GiB. Runs exceeding it are stopped and their shard
+    /// size rejected.
+    #[arg(long)]
+    memory_limit: Option<f64>,
+    /// The results file of the proving runs, as with `--filename`. The execute-only runs go to
+    /// `<filename>_execute`.
+    #[arg(long, default_value = "tune")]
+    filename: String,
+    #[arg(long)]
+    powdr_no_continuations: bool,
+    #[arg(long, default_value = "native")]
+    powdr_linker: PowdrLinker,
+    #[arg(long, default_value = "vadcop")]
+    powdr_degree_mode: PowdrDegreeMode,
+    /// A prover option as `key=value`, passed on to every run.
+    #[arg(long = "prover-opt", value_parser = config::parse_prover_opt)]
+    prover_opts: Vec<(String, String)>,
+    /// A file of prover options, passed on to every run.
+    #[arg(long)]
+    prover_opts_file: Option<PathBuf>,
+    /// The setup cache of the runs, as with `--setup-cache`.
+    #[arg(long)]
+    setup_cache: Option<PathBuf>,
+    #[arg(long, conflicts_with = "program_inputs")]
+    bf_case: Option<BrainfuckCase>,
+    program_inputs: Vec<String>,
+}
+
+/// How a run of the benchmark ended.
+enum Outcome {
+    /// The row the run appended to the results file, and its peak memory in bytes.
+    Done(Box<ResultRow>, u64),
+    Failed,
+    /// The run was stopped when its memory exceeded the limit.
+    OutOfMemory(u64),
+}
+
+fn format_memory(bytes: u64) -> String {
+    format!("{:.2} GiB", bytes as f64 / (1u64 << 30) as f64)
+}
+
+impl TuneArgs {
+    /// Runs the benchmark at a shard size, either only executing the program or generating the
+    /// core proof, and stops it if its memory exceeds `memory_limit`.
+    fn run(&self, shard_size: u64, execute_only: bool, memory_limit: Option<u64>) -> Outcome {
+        let filename =
+            if execute_only { format!("{}_execute", self.filename) } else { self.filename.clone() };
+        let mut command = Command::new(std::env::current_exe().unwrap());
+        command.args(["--program", &self.program.to_string()]);
+        command.args(["--prover", &self.prover.to_string()]);
+        command.args(["--hashfn", &self.hashfn.to_string()]);
+        command.args(["--shard-size", &shard_size.to_string()]);
+        command.args(["--filename", &filename]);
+        command.arg(if execute_only { "--execute-only" } else { "--core-only" });
+        if self.powdr_no_continuations {
+            command.arg("--powdr-no-continuations");
+        }
+        command.args(["--powdr-linker", &self.powdr_linker.to_string()]);
+        command.args(["--powdr-degree-mode", &self.powdr_degree_mode.to_string()]);
+        for (key, value) in &self.prover_opts {
+            command.args(["--prover-opt", &format!("{key}={value}")]);
+        }
+        if let Some(path) = &self.prover_opts_file {
+            command.arg("--prover-opts-file").arg(path);
+        }
+        if let Some(path) = &self.setup_cache {
+            command.arg("--setup-cache").arg(path);
+        }
+        if let Some(case) = self.bf_case {
+            command.args(["--bf-case", &case.to_string()]);
+        }
+        command.args(&self.program_inputs);
+
+        // the output of each run goes to its own log, next to the results
+        let log = PathBuf::from("benchmarks")
+            .join(format!("{}_{}_{}_{}.log", filename, self.program, self.prover, shard_size));
+        let log = File::create(&log).unwrap_or_else(|_| panic!("Failed to create {:?}", log));
+        command.stdout(log.try_clone().unwrap()).stderr(log);
+
+        let mut child = command.spawn().expect("Failed to start the benchmark");
+        let mut peak = 0;
+        let status = loop {
+            if let Some(status) = child.try_wait().unwrap() {
+                break status;
+            }
+            peak = peak.max(peak_memory(child.id()).unwrap_or(0));
+            if memory_limit.is_some_and(|limit| peak > limit) {
+                child.kill().unwrap();
+                child.wait().unwrap();
+                return Outcome::OutOfMemory(peak);
+            }
+            thread::sleep(Duration::from_millis(100));
+        };
+        if !status.success() {
+            return Outcome::Failed;
+        }
+        let row = read_results(&results_path(&filename)).pop().expect("The run wrote no results");
+        Outcome::Done(Box::new(row), peak)
+    }
+}
+
+pub fn run(args: &TuneArgs) {
+    assert!(args.min_shard_size <= args.max_shard_size, "Empty shard size range");
+    create_dir_all("benchmarks").unwrap();
+    let memory_limit = args.memory_limit.map(|gib| (gib * (1u64 << 30) as f64) as u64);
+
+    // Executing is cheap compared to proving, and tells the shards of each shard size. Once the
+    // program fits in one shard, larger shards only add padding, so they are not tried.
+    let mut candidates = vec![];
+    for shard_size in args.min_shard_size..=args.max_shard_size {
+        match args.run(shard_size, true, None) {
+            Outcome::Done(row, _) => {
+                let shards = row.metric("shards").unwrap_or(0.0);
+                println!("shard size {shard_size}: executed, {shards} shards");
+                candidates.push(shard_size);
+                if shards <= 1.0 {
+                    break;
+                }
+            }
+            _ => println!("shard size {shard_size}: execution failed, skipped"),
+        }
+    }
+
+    // Memory grows with the shard size, so once a shard size exceeds the limit, larger ones are
+    // not tried either.
+    let mut results = vec![];
+    for &shard_size in &candidates {
+        let outcome = args.run(shard_size, false, memory_limit);
+        match &outcome {
+            Outcome::Done(row, peak) => println!(
+                "shard size {shard_size}: core proving {:.2} s, peak memory {}",
+                row.metric("core_prove_duration").unwrap_or(f64::NAN),
+                format_memory(*peak)
+            ),
+            Outcome::Failed => println!("shard size {shard_size}: proving failed"),
+            Outcome::OutOfMemory(peak) => {
+                println!("shard size {shard_size}: stopped at {}", format_memory(*peak))
+            }
+        }
+        let out_of_memory = matches!(outcome, Outcome::OutOfMemory(_));
+        results.push((shard_size, outcome));
+        if out_of_memory {
+            break;
+        }
+    }
+
+    println!();
+    println!("| shard size | shards | core proving | peak memory |");
+    println!("|---:|---:|---:|---:|");
+    for (shard_size, outcome) in &results {
+        match outcome {
+            Outcome::Done(row, peak) => println!(
+                "| {shard_size} | {} | {:.2} s | {} |",
+                row.metric("shards").unwrap_or(0.0),
+                row.metric("core_prove_duration").unwrap_or(f64::NAN),
+                format_memory(*peak)
+            ),
+            Outcome::Failed => println!("| {shard_size} | | failed | |"),
+            Outcome::OutOfMemory(peak) => {
+                println!("| {shard_size} | | out of memory | > {} |", format_memory(*peak))
+            }
+        }
+    }
+
+    let best = results
+        .iter()
+        .filter_map(|(shard_size, outcome)| match outcome {
+            Outcome::Done(row, _) if row.status == RunStatus::Ok => {
+                Some((*shard_size, row.metric("core_prove_duration")?))
+            }
+            _ => None,
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b));
+    match best {
+        Some((shard_size, duration)) => println!(
+            "\nfastest shard size for {} on {}: {shard_size} ({duration:.2} s core proving)",
+            args.program, args.prover
+        ),
+        None => println!("\nno shard size could be proven"),
+    }
+}
@@ -1,3 +1,5 @@
+use std::fmt;
+
 /// An identifier used to select the program to evaluate.
 #[derive(clap::ValueEnum, Clone, PartialEq)]
 #[clap(rename_all = "kebab_case")]
@@ -15,6 +17,21 @@ pub enum ProgramId {
     BrainfuckAsm,
     // brainfuck compiled into Powdr ASM
     BrainfuckCompiler,
+    // brainfuck compiled into Powdr ASM with run-length and loop idioms folded
+    BrainfuckCompilerOpt,
+}
+
+/// A program of the brainfuck corpus in `programs/brainfuck/corpus`, with its input and expected
+/// output.
+#[derive(clap::ValueEnum, Clone, Copy, PartialEq)]
+#[clap(rename_all = "kebab_case")]
+pub enum BrainfuckCase {
+    HelloWorld,
+    Rot13,
+    Squares,
+    Mandelbrot,
+    // a brainfuck interpreter written in brainfuck, running hello world
+    SelfInterpreter,
 }
 
 /// An identifier used to select the prover to evaluate.
@@ -27,54 +44,127 @@ pub enum ProverId {
     PowdrPlonky3,
 }
 
+/// The linker powdr connects the machines of a program with.
+#[derive(clap::ValueEnum, Clone, Copy, PartialEq, Debug, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PowdrLinker {
+    // the machines are connected with lookups and permutations
+    Native,
+    // the machines are connected through a bus
+    Bus,
+}
+
+/// How powdr sizes the machines of a program.
+#[derive(clap::ValueEnum, Clone, Copy, PartialEq, Debug, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PowdrDegreeMode {
+    // each machine has its own size, up to the maximum degree
+    Vadcop,
+    // every machine has the maximum degree
+    Monolithic,
+}
+
 /// An identifier used to select the hash function to evaluate.
 #[derive(clap::ValueEnum, Clone, PartialEq)]
 pub enum HashFnId {
+    // also accepted as written in the results
+    #[value(alias = "sha-256")]
     Sha256,
     Poseidon,
+    Blake2b,
     Blake3,
     Keccak256,
 }
 
-impl ProgramId {
-    /// Convert the identifier to a string.
-    pub fn to_string(&self) -> String {
-        match self {
-            ProgramId::Loop => "loop".to_string(),
-            ProgramId::Fibonacci => "fibonacci".to_string(),
-            ProgramId::SSZWithdrawals => "ssz-withdrawals".to_string(),
-            ProgramId::Tendermint => "tendermint".to_string(),
-            ProgramId::Sha2Chain => "sha2-chain".to_string(),
-            ProgramId::Reth => "reth".to_string(),
-            ProgramId::Keccak => "keccak".to_string(),
-            ProgramId::Brainfuck => "brainfuck".to_string(),
-            ProgramId::BrainfuckAsm => "brainfuck-asm".to_string(),
-            ProgramId::BrainfuckCompiler => "brainfuck-compiler".to_string(),
-        }
+impl fmt::Display for ProgramId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            ProgramId::Loop => "loop",
+            ProgramId::Fibonacci => "fibonacci",
+            ProgramId::SSZWithdrawals => "ssz-withdrawals",
+            ProgramId::Tendermint => "tendermint",
+            ProgramId::Sha2Chain => "sha2-chain",
+            ProgramId::Reth => "reth",
+            ProgramId::Keccak => "keccak",
+            ProgramId::Brainfuck => "brainfuck",
+            ProgramId::BrainfuckAsm => "brainfuck-asm",
+            ProgramId::BrainfuckCompiler => "brainfuck-compiler",
+            ProgramId::BrainfuckCompilerOpt => "brainfuck-compiler-opt",
+        };
+        f.write_str(name)
+    }
+}
+
+impl fmt::Display for ProverId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            ProverId::Risc0 => "risc0",
+            ProverId::SP1 => "sp1",
+            // ProverId::JoltZkvm => "jolt-zkvm",
+            ProverId::PowdrEstark => "powdr-estark",
+            ProverId::PowdrPlonky3 => "powdr-plonky3",
+        };
+        f.write_str(name)
     }
 }
 
 impl ProverId {
-    /// Convert the identifier to a string.
-    pub fn to_string(&self) -> String {
+    /// Whether the prover can commit to its traces with the hash function. RISC0 offers a choice
+    /// in its prover options, while SP1 and powdr are built around a Poseidon variant.
+    pub fn supports_hashfn(&self, hashfn: &HashFnId) -> bool {
         match self {
-            ProverId::Risc0 => "risc0".to_string(),
-            ProverId::SP1 => "sp1".to_string(),
-            // ProverId::JoltZkvm => "jolt-zkvm".to_string(),
-            ProverId::PowdrEstark => "powdr-estark".to_string(),
-            ProverId::PowdrPlonky3 => "powdr-plonky3".to_string(),
+            ProverId::Risc0 => {
+                matches!(hashfn, HashFnId::Sha256 | HashFnId::Poseidon | HashFnId::Blake2b)
+            }
+            ProverId::SP1 | ProverId::PowdrEstark | ProverId::PowdrPlonky3 => {
+                *hashfn == HashFnId::Poseidon
+            }
         }
     }
 }
 
-impl HashFnId {
-    /// Convert the identifier to a string.
-    pub fn to_string(&self) -> String {
-        match self {
-            HashFnId::Sha256 => "sha-256".to_string(),
-            HashFnId::Poseidon => "poseidon".to_string(),
-            HashFnId::Blake3 => "blake3".to_string(),
-            HashFnId::Keccak256 => "keccak256".to_string(),
-        }
+impl fmt::Display for PowdrLinker {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            PowdrLinker::Native => "native",
+            PowdrLinker::Bus => "bus",
+        };
+        f.write_str(name)
+    }
+}
+
+impl fmt::Display for PowdrDegreeMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            PowdrDegreeMode::Vadcop => "vadcop",
+            PowdrDegreeMode::Monolithic => "monolithic",
+        };
+        f.write_str(name)
+    }
+}
+
+impl fmt::Display for HashFnId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            HashFnId::Sha256 => "sha-256",
+            HashFnId::Poseidon => "poseidon",
+            HashFnId::Blake2b => "blake2b",
+            HashFnId::Blake3 => "blake3",
+            HashFnId::Keccak256 => "keccak256",
+        };
+        f.write_str(name)
+    }
+}
+
+impl fmt::Display for BrainfuckCase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            BrainfuckCase::HelloWorld => "hello-world",
+            BrainfuckCase::Rot13 => "rot13",
+            BrainfuckCase::Squares => "squares",
+            BrainfuckCase::Mandelbrot => "mandelbrot",
+            BrainfuckCase::SelfInterpreter => "self-interpreter",
+        };
+        f.write_str(name)
     }
 }
@@ -1,9 +1,13 @@
 use core::time;
-use std::{collections::VecDeque, env, fs, path::Path, time::Instant};
+use std::{env, fs, time::Instant};
 
 use sp1_reth_primitives::SP1RethInput;
+use ssz_withdrawals_input::WithdrawalsInput;
 
-use crate::{EvalArgs, ProgramId, ProverId};
+use crate::{
+    blocks::{list_blocks, read_block},
+    EvalArgs, ProgramId, ProverId,
+};
 
 #[allow(unused)]
 pub fn get_elf(args: &EvalArgs) -> String {
@@ -32,32 +36,37 @@ pub fn get_elf(args: &EvalArgs) -> String {
     elf_path_str
 }
 
-pub fn get_reth_input(args: &EvalArgs) -> SP1RethInput {
-    let block_number = match &args.program_inputs[..] {
+pub fn get_reth_block_number(args: &EvalArgs) -> u64 {
+    match &args.program_inputs[..] {
         [block_number] => block_number.parse::<u64>().expect("Invalid reth block number"),
         _ => panic!("Block number is required for Reth program"),
-    };
-
-    let current_dir = env::current_dir().expect("Failed to get current working directory");
-
-    let blocks_dir = current_dir.join("eval").join("blocks");
-
-    let file_path = blocks_dir.join(format!("{}.bin", block_number));
-
-    if let Ok(bytes) = fs::read(file_path) {
-        bincode::deserialize(&bytes).expect("Unable to deserialize input")
-    } else {
-        let blocks: Vec<String> = fs::read_dir(&blocks_dir)
-            .unwrap_or_else(|_| panic!("Failed to read blocks directory: {:?}", blocks_dir))
-            .filter_map(|entry| {
-                entry
-                    .ok()
-                    .and_then(|e| e.path().file_stem().and_then(|n| n.to_str().map(String::from)))
-            })
-            .collect();
+    }
+}
 
+pub fn get_reth_input(args: &EvalArgs) -> SP1RethInput {
+    let block_number = get_reth_block_number(args);
+    read_block(block_number).unwrap_or_else(|| {
+        let blocks: Vec<String> = list_blocks().iter().map(|b| b.to_string()).collect();
         panic!("Block {} not supported. Please choose from: {}", block_number, blocks.join(", "));
-    }
+    })
+}
+
+/// Reads the withdrawals input file for the ssz-withdrawals program. The JSON is parsed here so
+/// that the guest only receives the typed witness data.
+pub fn get_ssz_withdrawals_input(args: &EvalArgs) -> WithdrawalsInput {
+    let path = match &args.program_inputs[..] {
+        [path] => path,
+        _ => panic!("An input file is required for the ssz-withdrawals program"),
+    };
+    let json = fs::read(path).unwrap_or_else(|_| {
+        panic!(
+            "Failed to read withdrawals input: {}, generate it with \
+             programs/ssz-withdrawals/gen_input.py",
+            path
+        )
+    });
+    ssz_withdrawals_input::json::from_slice(&json)
+        .unwrap_or_else(|e| panic!("Failed to parse withdrawals input {}: {}", path, e))
 }
 
 pub fn time_operation<T, F: FnOnce() -> T>(operation: F) -> (T, time::Duration) {
@@ -67,78 +76,10 @@ pub fn time_operation<T, F: FnOnce() -> T>(operation: F) -> (T, time::Duration)
     (result, duration)
 }
 
-fn read_brainfuck_and_convert(path: &Path) -> Vec<u32> {
-    let content = fs::read_to_string(path).expect("error reading brainfuck program");
-    let valid_chars = "><+-.,[]";
-    content
-        .chars()
-        .filter(|c| valid_chars.contains(*c))
-        .map(|b| b as u32)
-        // interpreter stops at seeing a 0
-        .chain(std::iter::once(0))
-        .collect()
-}
-
-fn read_brainfuck_inputs(path: &Path) -> VecDeque<u32> {
-    let content = fs::read_to_string(path).expect("error reading brainfuck input file");
-    content
-        .split(',')
-        .map(|x| x.trim())
-        .filter(|x| !x.is_empty())
-        .map(|x| x.parse::<u32>().unwrap())
-        .collect()
-}
-
-pub fn get_brainfuck_input(args: &EvalArgs) -> (Vec<u32>, VecDeque<u32>) {
-    match &args.program_inputs[..] {
-        [program] => (read_brainfuck_and_convert(program.as_ref()), Default::default()),
-        [program, input] => {
-            (read_brainfuck_and_convert(program.as_ref()), read_brainfuck_inputs(input.as_ref()))
-        }
-        _ => panic!("Brainfuck interpreter requires a program and possibly inputs"),
-    }
-}
-
-/// compile brainfuck into powdr asm instructions for the custom brainfuck vm
-#[cfg(any(feature = "powdr-plonky3", feature = "powdr-estark"))]
-pub fn compile_brainfuck(program: &[u32]) -> String {
-    let mut loop_stack = vec![];
-    let mut loop_counter = 0;
-    let mut asm = vec![];
-    for instr in program.into_iter().map(|c| std::char::from_u32(*c).unwrap()) {
-        // match each brainfuck character
-        match instr {
-            '>' => asm.push("inc_dp;".to_string()),
-            '<' => asm.push("dec_dp;".to_string()),
-            '+' => asm.push("inc_cell;".to_string()),
-            '-' => asm.push("dec_cell;".to_string()),
-            ',' => {
-                asm.push("read in_remaining;".to_string());
-            }
-            '.' => {
-                asm.push("data <== mload();".to_string());
-                asm.push(
-                    "data <=X= ${ Query::Output(1, std::convert::int(std::prover::eval(data))) };"
-                        .to_string(),
-                );
-            }
-            '[' => {
-                let label_true = format!("loop_true_{loop_counter}");
-                let label_false = format!("loop_false_{loop_counter}");
-                loop_counter += 1;
-                asm.push(format!("{label_true}:"));
-                asm.push("data <== mload();".to_string());
-                asm.push(format!("branch_if_zero data, {label_false};"));
-                loop_stack.push((label_true, label_false));
-            }
-            ']' => {
-                let (label_true, label_false) = loop_stack.pop().expect("unmatched ] in program");
-                asm.push(format!("jump {label_true};"));
-                asm.push(format!("{label_false}:"))
-            }
-            _ => (), // ignore other characters
-        }
-    }
-    asm.push("return;".to_string());
-    asm.join("\n        ")
+/// Reads the peak resident memory of a process in bytes, from `VmHWM` in `/proc/<pid>/status`.
+pub fn peak_memory(pid: u32) -> Option<u64> {
+    let status = fs::read_to_string(format!("/proc/{pid}/status")).ok()?;
+    let line = status.lines().find_map(|line| line.strip_prefix("VmHWM:"))?;
+    let kib: u64 = line.trim().trim_end_matches("kB").trim().parse().ok()?;
+    Some(kib * 1024)
 }
@@ -0,0 +1,187 @@
+//! A parsed brainfuck program, shared by the evaluator, which validates the programs and encodes
+//! them for the RISC-V interpreter and the handwritten powdr VM, and by the powdr compilers. The
+//! RISC-V interpreter itself runs over the encoded program, so its workload is unchanged.
+
+use std::fmt;
+
+/// A brainfuck operation. Loops are nested, so a parsed program always has matching brackets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Node {
+    /// `>`
+    Right,
+    /// `<`
+    Left,
+    /// `+`
+    Inc,
+    /// `-`
+    Dec,
+    /// `,`
+    Read,
+    /// `.`
+    Write,
+    /// `[...]`
+    Loop(Vec<Node>),
+}
+
+/// A parsed brainfuck program.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Program {
+    pub nodes: Vec<Node>,
+}
+
+/// A 1-based line and column in the program source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// An error found while parsing a brainfuck program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// A `]` without a matching `[`.
+    UnmatchedClose(Position),
+    /// A `[` without a matching `]`.
+    UnmatchedOpen(Position),
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnmatchedClose(pos) => write!(f, "{pos}: unmatched `]`"),
+            ParseError::UnmatchedOpen(pos) => write!(f, "{pos}: unmatched `[`"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl Program {
+    /// Parses brainfuck source code. Characters other than `><+-.,[]` are comments.
+    pub fn parse(source: &str) -> Result<Program, ParseError> {
+        let mut line = 1;
+        let mut column = 0;
+        let tokens = source.chars().filter_map(|c| {
+            if c == '\n' {
+                line += 1;
+                column = 0;
+                return None;
+            }
+            column += 1;
+            Some((c, Position { line, column }))
+        });
+        parse_tokens(tokens)
+    }
+
+    /// Encodes the program as the ASCII codes of its characters, terminated by a 0. This is the
+    /// input format of the RISC-V interpreters and of the handwritten powdr VM.
+    pub fn encode(&self) -> Vec<u32> {
+        fn encode_nodes(nodes: &[Node], opcodes: &mut Vec<u32>) {
+            for node in nodes {
+                match node {
+                    Node::Right => opcodes.push('>' as u32),
+                    Node::Left => opcodes.push('<' as u32),
+                    Node::Inc => opcodes.push('+' as u32),
+                    Node::Dec => opcodes.push('-' as u32),
+                    Node::Read => opcodes.push(',' as u32),
+                    Node::Write => opcodes.push('.' as u32),
+                    Node::Loop(body) => {
+                        opcodes.push('[' as u32);
+                        encode_nodes(body, opcodes);
+                        opcodes.push(']' as u32);
+                    }
+                }
+            }
+        }
+
+        let mut opcodes = vec![];
+        encode_nodes(&self.nodes, &mut opcodes);
+        // interpreters stop at seeing a 0
+        opcodes.push(0);
+        opcodes
+    }
+}
+
+/// Builds the nested nodes from brainfuck characters, matching the brackets.
+fn parse_tokens(tokens: impl Iterator<Item = (char, Position)>) -> Result<Program, ParseError> {
+    // the open loops, with the position of their `[` and the nodes before it
+    let mut open_loops: Vec<(Position, Vec<Node>)> = vec![];
+    let mut nodes = vec![];
+    for (c, position) in tokens {
+        let node = match c {
+            '>' => Node::Right,
+            '<' => Node::Left,
+            '+' => Node::Inc,
+            '-' => Node::Dec,
+            ',' => Node::Read,
+            '.' => Node::Write,
+            '[' => {
+                open_loops.push((position, std::mem::take(&mut nodes)));
+                continue;
+            }
+            ']' => {
+                let (_, outer) = open_loops.pop().ok_or(ParseError::UnmatchedClose(position))?;
+                Node::Loop(std::mem::replace(&mut nodes, outer))
+            }
+            _ => continue, // ignore other characters
+        };
+        nodes.push(node);
+    }
+    match open_loops.pop() {
+        Some((position, _)) => Err(ParseError::UnmatchedOpen(position)),
+        None => Ok(Program { nodes }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(line: usize, column: usize) -> Position {
+        Position { line, column }
+    }
+
+    #[test]
+    fn parses_nested_loops() {
+        let program = Program::parse("+[>[-]<]. comment").unwrap();
+        assert_eq!(
+            program.nodes,
+            vec![
+                Node::Inc,
+                Node::Loop(vec![Node::Right, Node::Loop(vec![Node::Dec]), Node::Left]),
+                Node::Write,
+            ]
+        );
+    }
+
+    #[test]
+    fn unmatched_close_position() {
+        // comments count as columns, newlines start a new line
+        assert_eq!(Program::parse("+ a\n[-]]"), Err(ParseError::UnmatchedClose(at(2, 4))));
+    }
+
+    #[test]
+    fn unmatched_open_position() {
+        // the innermost loop that is not closed is reported
+        assert_eq!(Program::parse("[\n  [[-]\n"), Err(ParseError::UnmatchedOpen(at(2, 3))));
+    }
+
+    #[test]
+    fn error_display() {
+        let err = Program::parse("\n\n  ]").unwrap_err();
+        assert_eq!(err.to_string(), "3:3: unmatched `]`");
+    }
+
+    #[test]
+    fn encodes_without_comments() {
+        let program = Program::parse("++[>,.<-] comment").unwrap();
+        let expected: Vec<u32> = "++[>,.<-]".chars().map(|c| c as u32).chain([0]).collect();
+        assert_eq!(program.encode(), expected);
+    }
+}
@@ -7,8 +7,9 @@ machine Brainfuck {
 
     // the program pc
     reg pc[@pc];
-    // assignment register used by instruction parameters
+    // assignment registers used by instruction parameters
     reg X[<=];
+    reg Y[<=];
 
     // data pointer
     reg dp;
@@ -42,9 +43,11 @@ machine Brainfuck {
 
     instr inc_dp { dp' = dp + 1 }
     instr dec_dp { dp' = dp - 1 }
+    instr move_dp X { dp' = dp + X }
 
-    // helper column
+    // helper columns
     col witness C;
+    col witness D;
 
     col witness Input(unused) query Query::Input(to_int(std::prover::eval(in_count - in_remaining) + 1) % (to_int(std::prover::eval(in_count)) + 1));
 
@@ -65,6 +68,23 @@ machine Brainfuck {
        link ~> C = mem.mload(dp, STEP)
        link ~> mem.mstore(dp, STEP, C - 1);
 
+    // instructions used by the optimising compiler
+
+    // adds X to the current cell (folded runs of `+` and `-`)
+    instr add_cell X
+       link ~> C = mem.mload(dp, STEP)
+       link ~> mem.mstore(dp, STEP, C + X);
+
+    // sets the current cell to zero (`[-]`)
+    instr clear_cell
+       link ~> mem.mstore(dp, STEP, 0);
+
+    // adds the current cell times Y to the cell at offset X (multiply-move loops like `[->++<]`)
+    instr mul_add_cell X, Y
+       link ~> C = mem.mload(dp, STEP)
+       link ~> D = mem.mload(dp + X, STEP)
+       link ~> mem.mstore(dp + X, STEP, D + C * Y);
+
     // memory instructions
     col fixed STEP(i) { i };
     instr mload -> X
@@ -5,26 +5,30 @@
 /// - Re-writing some routines to use fewer rows
 
 /// Soundness considerations:
-/// - The opcodes are currently unconstrained, meaning the prover can provide whatever they want.
-///   To fix this, a public commitment to the program needs to be passed and verified in the machine below.
+/// - The program is read from the prover input, so the machine commits to it: the program words
+///   are hashed with Poseidon in blocks of 8, starting from a state holding the program length,
+///   and the resulting hash is exposed as the publics `program_hash_0..3`.
+///   The verifier must check these publics against the hash of the expected program.
 
 /// Program and input/output encoding:
 /// The prover input is a list of numbers encoded as follows
 /// <program_length> <program> <input_length> <input>
-/// where <program> needs to end with a 0
+/// where <program> needs to end with a 0 and is padded with zeros to a multiple of 8 words.
 /// Example:
-/// [2, 44, 0, 1, 97]
-/// This program has length 2, where the program is [44, 0] (read, finish)
+/// [8, 44, 0, 0, 0, 0, 0, 0, 0, 1, 97]
+/// This program has length 8, where the program is [44, 0] (read, finish) plus padding
 /// and the input list is [97].
 /// The `.` (print) instruction treats its input as the ASCII code of a character,
 /// and prints that character.
 
 use std::machines::range::Byte2;
 use std::machines::memory::Memory;
+use std::machines::hash::poseidon_gl::PoseidonGL;
 
 machine Brainfuck {
 	Byte2 byte2;
 	Memory mem(byte2);
+	PoseidonGL poseidon_gl;
 
 	reg pc[@pc];
 	reg X[<=];
@@ -48,10 +52,25 @@ machine Brainfuck {
 	reg CNT;
 	reg tmp1;
 
+	// Program hash registers: the 8 words absorbed per block and the 4 word hash
+	reg w0;
+	reg w1;
+	reg w2;
+	reg w3;
+	reg w4;
+	reg w5;
+	reg w6;
+	reg w7;
+	reg h0;
+	reg h1;
+	reg h2;
+	reg h3;
+
 	instr jump l: label -> Y { pc' = l, Y = pc + 1}
 	instr jump_dyn X -> Y { pc' = X, Y = pc + 1}
 	instr branch_if_zero X, l: label { pc' = XIsZero * l + (1 - XIsZero) * (pc + 1) }
 	instr fail { 1 = 0 }
+	instr assert_zero X { XIsZero = 1 }
 
 	// ============== memory instructions ==============
 	col fixed STEP(i) { i };
@@ -64,6 +83,16 @@ machine Brainfuck {
 	// ============== iszero check for X =======================
 	let XIsZero = std::utils::is_zero(X);
 
+	// ============== program commitment =======================
+	instr poseidon
+		link ~> (h0', h1', h2', h3') = poseidon_gl.poseidon_permutation(w0, w1, w2, w3, w4, w5, w6, w7, h0, h1, h2, h3);
+
+	// the hash registers are never written after the program is hashed
+	public program_hash_0 = h0(%N - 1);
+	public program_hash_1 = h1(%N - 1);
+	public program_hash_2 = h2(%N - 1);
+	public program_hash_3 = h3(%N - 1);
+
 	// === Brainfuck interpreter ==========
 	function main {
 		// calls the main entry point of the program
@@ -84,6 +113,30 @@ machine Brainfuck {
 			CNT <=X= CNT + 1;
 			tmp1 <== jump(read_program_loop);
 		end_read_program:
+			// the program must end with a 0
+			tmp1 <== mload(A - 1);
+			assert_zero tmp1;
+			// hash the program in blocks of 8 words, starting from its length. The registers are
+			// unconstrained on the first row, so the rest of the capacity is reset explicitly.
+			h0 <=X= A;
+			h1 <=X= 0;
+			h2 <=X= 0;
+			h3 <=X= 0;
+			CNT <=X= 0;
+		hash_program_loop:
+			branch_if_zero A - CNT, end_hash_program;
+			w0 <== mload(CNT + 0);
+			w1 <== mload(CNT + 1);
+			w2 <== mload(CNT + 2);
+			w3 <== mload(CNT + 3);
+			w4 <== mload(CNT + 4);
+			w5 <== mload(CNT + 5);
+			w6 <== mload(CNT + 6);
+			w7 <== mload(CNT + 7);
+			poseidon;
+			CNT <=X= CNT + 8;
+			tmp1 <== jump(hash_program_loop);
+		end_hash_program:
 		read_input:
 			CNT <=X= 0;
 			// read input length
@@ -227,7 +280,6 @@ machine Brainfuck {
 
 		// ==== main interpreter loop
 		interpreter_loop:
-			// TODO we should also hash the program and expose as public
 			op <== mload(b_pc);
 
 			branch_if_zero op, exit;
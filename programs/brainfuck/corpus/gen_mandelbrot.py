@@ -0,0 +1,334 @@
+"""Generate `mandelbrot.bf`, a small ASCII rendering of the Mandelbrot set.
+
+The program is emitted by a tiny macro assembler over named cells. It only relies on cells
+holding non-negative integers, never on cell wrap-around, so it runs unchanged on the RISC-V
+interpreter and on the powdr brainfuck VMs. Numbers are fixed-point with `SCALE` units per 1.0
+and stored as a sign cell and a magnitude cell.
+
+    python3 gen_mandelbrot.py > mandelbrot/program.bf
+"""
+
+SCALE = 8
+ITERATIONS = 7
+# The characters printed for points escaping after 0, 1, ... iterations, and for points in the set.
+RAMP = ' .:-=+*#'
+# The top-left corner of the image and the size of a character cell, in fixed-point units.
+X0, Y0 = -2 * SCALE, -1 * SCALE
+COLUMNS, ROWS = 21, 9
+DX, DY = 1, 2
+
+
+# The six cells used by `div`. The dividend is read from the first one. The block sits right after
+# the iterated point and two spare cells, so that the squares are accumulated close to their
+# operands.
+DIV_BLOCK = 6
+
+
+class Assembler:
+    def __init__(self):
+        self.code = []
+        self.ptr = 0
+        self.free = [c for c in range(64) if not DIV_BLOCK <= c < DIV_BLOCK + 6]
+
+    def alloc(self, near=None):
+        """Allocates the free cell closest to `near`, or the lowest free cell after the division
+        block. Keeping temporaries close to their operands keeps the pointer moves short."""
+        if near is None:
+            key = lambda c: (c < DIV_BLOCK, c)
+        else:
+            key = lambda c: (abs(c - near), c)
+        cell = min(self.free, key=key)
+        self.free.remove(cell)
+        return cell
+
+    def alloc_padded(self, near):
+        """Allocates the cell closest to `near` that is followed by two free cells, for
+        `nonzero`."""
+        cells = [c for c in self.free if c + 1 in self.free and c + 2 in self.free]
+        cell = min(cells, key=lambda c: (abs(c - near), c))
+        for c in (cell, cell + 1, cell + 2):
+            self.free.remove(c)
+        return cell
+
+    def release_padded(self, cell):
+        self.release(cell, cell + 1, cell + 2)
+
+    def release(self, *cells):
+        # cells are only released once zero
+        self.free.extend(cells)
+
+    def goto(self, cell):
+        self.code.append('>' * (cell - self.ptr) if cell > self.ptr else '<' * (self.ptr - cell))
+        self.ptr = cell
+
+    def add(self, cell, k):
+        self.goto(cell)
+        self.code.append('+' * k if k > 0 else '-' * -k)
+
+    def clear(self, cell):
+        self.goto(cell)
+        self.code.append('[-]')
+
+    def loop(self, cell, body):
+        self.goto(cell)
+        self.code.append('[')
+        body()
+        self.goto(cell)
+        self.code.append(']')
+
+    def move(self, src, *dsts):
+        """Adds `src` to every cell of `dsts` and clears `src`."""
+
+        def body():
+            self.add(src, -1)
+            for dst in dsts:
+                self.add(dst, 1)
+
+        self.loop(src, body)
+
+    def copy(self, src, *dsts):
+        """Adds `src` to every cell of `dsts`."""
+        tmp = self.alloc(near=src)
+        self.move(src, tmp, *dsts)
+        self.move(tmp, src)
+        self.release(tmp)
+
+    def set(self, cell, k):
+        self.clear(cell)
+        self.add(cell, k)
+
+    def if_(self, cond, then):
+        """Runs `then` if `cond` is non-zero."""
+        tmp = self.alloc(near=cond)
+        self.copy(cond, tmp)
+
+        def body():
+            then()
+            self.clear(tmp)
+
+        self.loop(tmp, body)
+        self.release(tmp)
+
+    def if_else(self, cond, then, otherwise):
+        """Runs `then` if `cond` is non-zero, `otherwise` if it is zero."""
+        flag = self.alloc()
+        self.add(flag, 1)
+
+        def then_body():
+            self.add(flag, -1)
+            then()
+
+        self.if_(cond, then_body)
+
+        def else_body():
+            self.add(flag, -1)
+            otherwise()
+
+        self.loop(flag, else_body)
+        self.release(flag)
+
+    def nonzero(self, cell, dst):
+        """Adds 1 to `dst` if the padded `cell` is non-zero, in constant time. `[>-]>` leaves the
+        pointer one cell further right if `cell` is non-zero, so the following loop only runs on
+        zero and both paths end on the same cell."""
+        self.add(dst, 1)
+        self.add(cell + 1, 1)
+        self.goto(cell)
+        self.code.append('[>-]>[-')
+        self.ptr = cell + 1
+        self.add(dst, -1)
+        self.goto(cell + 2)
+        self.code.append(']')
+
+    def mul(self, a, b, dst):
+        """Adds `a * b` to `dst`."""
+        counter = self.alloc(near=dst)
+        self.copy(a, counter)
+
+        def body():
+            self.add(counter, -1)
+            self.copy(b, dst)
+
+        self.loop(counter, body)
+        self.release(counter)
+
+    def div(self, d, dst):
+        """Adds `n / d` to `dst`, for a constant `d` and `n` in the first cell of the division
+        block, which is cleared. This is the usual divmod idiom."""
+        n = DIV_BLOCK
+        self.add(n + 1, d)
+        self.goto(n)
+        self.code.append('[->-[>+>>]>[+[-<+>]>+>>]<<<<<]')
+        self.clear(n + 1)
+        self.clear(n + 2)
+        self.move(n + 3, dst)
+
+    def xor(self, a, b, dst):
+        """Adds `a ^ b` to `dst`, for bits `a` and `b`."""
+
+        def a_set():
+            self.add(dst, 1)
+            self.if_(b, lambda: self.add(dst, -1))
+
+        self.if_else(a, a_set, lambda: self.copy(b, dst))
+
+    def signed_add(self, a, b, dst):
+        """Adds the signed numbers `a` and `b`, each a (sign, magnitude) pair of cells, into the
+        cleared pair `dst`."""
+        (sa, ma), (sb, mb), (sd, md) = a, b, dst
+        differ = self.alloc()
+        self.xor(sa, sb, differ)
+
+        def subtract():
+            # count both magnitudes down until one of them is zero
+            x, y = self.alloc_padded(near=ma), self.alloc_padded(near=mb)
+            x_left, y_left, both = self.alloc(), self.alloc(), self.alloc()
+            self.copy(ma, x)
+            self.copy(mb, y)
+
+            def update_both():
+                self.nonzero(x, x_left)
+                self.nonzero(y, y_left)
+                self.if_(x_left, lambda: self.copy(y_left, both))
+                self.clear(x_left)
+                self.clear(y_left)
+
+            update_both()
+
+            def body():
+                self.add(x, -1)
+                self.add(y, -1)
+                self.clear(both)
+                update_both()
+
+            self.loop(both, body)
+
+            def a_larger():
+                self.copy(sa, sd)
+                self.move(x, md)
+
+            def b_larger():
+                self.copy(sb, sd)
+                self.move(y, md)
+
+            self.nonzero(x, x_left)
+            self.if_else(x_left, a_larger, b_larger)
+            self.clear(x_left)
+            self.release_padded(x)
+            self.release_padded(y)
+            self.release(x_left, y_left, both)
+
+        def same_sign():
+            self.copy(sa, sd)
+            self.copy(ma, md)
+            self.copy(mb, md)
+
+        self.if_else(differ, subtract, same_sign)
+        self.clear(differ)
+        self.release(differ)
+
+    def output(self, cell):
+        self.goto(cell)
+        self.code.append('.')
+
+
+def generate():
+    asm = Assembler()
+    alloc_pair = lambda: (asm.alloc(), asm.alloc())
+    # the iterated point goes before the division block, see `DIV_BLOCK`
+    x = asm.alloc(near=0), asm.alloc(near=0)
+    y = asm.alloc(near=0), asm.alloc(near=0)
+    row, column = asm.alloc(), asm.alloc()
+    cx, cy, step_x, step_y = alloc_pair(), alloc_pair(), alloc_pair(), alloc_pair()
+    alive, char = asm.alloc(), asm.alloc()
+
+    def assign_signed(dst, value):
+        asm.set(dst[0], int(value < 0))
+        asm.set(dst[1], abs(value))
+
+    def increment(var, step):
+        # var += step, through a temporary pair
+        tmp = alloc_pair()
+        asm.signed_add(var, step, tmp)
+        asm.clear(var[0])
+        asm.clear(var[1])
+        asm.move(tmp[0], var[0])
+        asm.move(tmp[1], var[1])
+        asm.release(*tmp)
+
+    def iterate(i):
+        x2, y2, t = alloc_pair(), asm.alloc(), asm.alloc()
+        asm.mul(x[1], x[1], DIV_BLOCK)
+        asm.div(SCALE, x2[1])
+        asm.mul(y[1], y[1], DIV_BLOCK)
+        asm.div(SCALE, y2)
+        # escape once x^2 + y^2 > 4
+        asm.copy(x2[1], DIV_BLOCK)
+        asm.copy(y2, DIV_BLOCK)
+        asm.div(4 * SCALE + 1, t)
+
+        def escape():
+            asm.clear(alive)
+
+        def step():
+            # y = 2xy + cy
+            xy = alloc_pair()
+            asm.xor(x[0], y[0], xy[0])
+            asm.mul(x[1], y[1], DIV_BLOCK)
+            asm.div(SCALE // 2, xy[1])
+            asm.clear(y[0])
+            asm.clear(y[1])
+            asm.signed_add(xy, cy, y)
+            asm.clear(xy[0])
+            asm.clear(xy[1])
+            # x = x^2 - y^2 + cx
+            asm.add(xy[0], 1)
+            asm.move(y2, xy[1])
+            asm.clear(x[0])
+            asm.clear(x[1])
+            asm.signed_add(x2, xy, x)
+            asm.clear(xy[0])
+            asm.clear(xy[1])
+            increment(x, cx)
+            asm.release(*xy)
+            asm.add(char, ord(RAMP[i + 1]) - ord(RAMP[i]))
+
+        asm.if_else(t, escape, step)
+        for cell in (x2[1], y2, t):
+            asm.clear(cell)
+        asm.release(*x2, y2, t)
+
+    def pixel():
+        asm.add(column, -1)
+        asm.clear(x[0])
+        asm.clear(x[1])
+        asm.clear(y[0])
+        asm.clear(y[1])
+        asm.set(char, ord(RAMP[0]))
+        asm.set(alive, 1)
+        for i in range(ITERATIONS):
+            asm.if_(alive, lambda: iterate(i))
+        asm.output(char)
+        increment(cx, step_x)
+
+    def line():
+        asm.add(row, -1)
+        assign_signed(cx, X0)
+        asm.set(column, COLUMNS)
+        asm.loop(column, pixel)
+        asm.set(char, ord('\n'))
+        asm.output(char)
+        increment(cy, step_y)
+
+    assign_signed(step_x, DX)
+    assign_signed(step_y, DY)
+    assign_signed(cy, Y0)
+    asm.set(row, ROWS)
+    asm.loop(row, line)
+    return ''.join(asm.code)
+
+
+if __name__ == '__main__':
+    code = generate()
+    for i in range(0, len(code), 80):
+        print(code[i:i + 80])
@@ -0,0 +1,668 @@
+>>>>>>>>>>>>>>>>>>[-]>[-]+>[-]>[-]++<<<<<[-]+>[-]++++++++<<<<<[-]+++++++++[->>[-
+]+>[-]++++++++++++++++<<[-]+++++++++++++++++++++[-<<<<<<<<<<<<<[-]>[-]>[-]>[-]>>
+>>>>>>>>>>>>>>>>>>[-]++++++++++++++++++++++++++++++++<[-]+[->>>+<+<<]>>>[-<<<+>>
+>]<[<<<<<<<<<<<<<<<<<<<<<<<[->>>+>+<<<<]>>>[-<<<+>>>]>[-<<<<[->>>+>>+<<<<<]>>>[-
+<<<+>>>]>]>>++++++++<[->-[>+>>]>[+[-<+>]>+>>]<<<<<]>[-]>[-]>[->>>>>>>>>>>>>>>>>+
+<<<<<<<<<<<<<<<<<]<<<<<<[->+>+<<]>[-<+>]>[-<<[->+>>+<<<]>[-<+>]>]>>++++++++<[->-
+[>+>>]>[+[-<+>]>+>>]<<<<<]>[-]>[-]>[->>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]>>>>>
+>>>>>>>>>>>>[->>>+<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]>>>[-<<<+>>>]<<[-
+>>+<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]>>[-<<+>>]<<<<<<<<<<<<<<<<<<<<<
+<+++++++++++++++++++++++++++++++++<[->-[>+>>]>[+[-<+>]>+>>]<<<<<]>[-]>[-]>[->>>>
+>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>+<[->>>+<+<<]>>>[-<<<+>>
+>]<[<-<<<<<<<[-]>>>>>>>>[-]]<[->>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>+<+<<<
+<]>>>>>[-<<<<<+>>>>>]<[>>>>>>>>>>>>>>>>>>>>>>>>>>>>-<<+<<<<<<<<<<<<<<<<<<<<<<<<<
+<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<+<<<]>>>>>>>>>>
+>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>
+>>>>>>]<<<<<<<<<<<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>>>>>>-<<<<<<<<<<<<<<<<<<
+<<<<<<<[-]]<[-]]>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>
++>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]>>[-<<+>>]>>>>>>>>>>>>>
+>>>>>>>>>>>>>>>]<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>+>+<<<<]>>>[-<<<+>>>]>[-<<[-
+>+>>+<<<]>[-<+>]>]>>++++<[->-[>+>>]>[+[-<+>]>+>>]<<<<<]>[-]>[-]>[->>>>>>>>>>>>>>
+>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<]<<<<<<<[-]>[-]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<
+[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[<-<+<<<<<<<<<<<<<<<<[-<<<<<<<<<<<<+>+>>>>>>>
+>>>>]<<<<<<<<<<<<[->>>>>>>>>>>>+<<<<<<<<<<<<]>[>>>>>>>>>>>>>>>>>>>>>>>>>>>-<<<<<
+<<<<<<<<<<<<<<<<<<<<<<[-]]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]]<[-<<<<<<<<<<<<<<<<<[
+-<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<]<<<<<<<<<<<[->>>>>>>>>
+>>+<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>]+<[->>>+<+<<]>>>[-<<<+>>>]<[<-<<[->>
+>>>>>>>>>>>+<<<<<<<<<+<<<<]>>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]<<<<<<<<<<
+<<<<<<<<<<<<<<<<<[-<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<
+<<<<<<<]<<<<<<<<<<<<[->>>>>>>>>>>>+<<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+>>>>+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+
+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>[<<<<<<<<->>>->>>>>[-]<<+<<<<<+
+<[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[-
+>>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>]<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>>>+<<<[-
+>>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[<-<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>+<<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>>>>>>>>>>>[
+-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]<<<<<<<<<<<[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
++>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>>>>>[-]]<[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<[
+-<<<<<<<<<<<+<<<+>>>>>>>>>>>>>>]<<<<<<<<<<<[->>>>>>>>>>>+<<<<<<<<<<<]>>>>>>>>>>>
+>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>
+>>>>>>>>>>>>>>>]>>>>>>]<<<[-]<<<<<<<[-]]<[-<<<[->>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<
+<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>[-<<<<+>>>>]<<<[->>>+<<<<<<<<<<<<<<<<<<<<
+<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>[-<<<+>>>]<<<<<<<<<<<<<<<<<[-<<<<<<<
+<<<<<+<<+>>>>>>>>>>>>>>]<<<<<<<<<<<<[->>>>>>>>>>>>+<<<<<<<<<<<<]>>>>>>>>>>>>>>>>
+>>>>>>>>>>>>]<[-]<<[-]>[-]<+<<<[->>>>+<<<<]<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]>[-]>>>
+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<[->>>>>>>>>>+<+<<<<<<<<<]>>>>>>>>>>[-<<<<<
+<<<<<+>>>>>>>>>>]<[<-<+<<[->>>>>>+<+<<<<<]>>>>>>[-<<<<<<+>>>>>>]<[<<<->>>[-]]<[-
+]]<[-<<<[->>>>+<<+<<]>>>>[-<<<<+>>>>]<]+<[->>>+<+<<]>>>[-<<<+>>>]<[<-<<<<<<<[->>
+>>>>>>>>>>>>>>>>+<<<<<<<<<+<<<<<<<<<]>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>
+>>>>>>>>>>>>>>]<<<<<<<<<<<<<[->>>>>>>>>>>>>+<<<<<<+<<<<<<<]>>>>>>>>>>>>>[-<<<<<<
+<<<<<<<+>>>>>>>>>>>>>]<<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->>>-<<]>[->
+>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>[<<<<<<<<-
+>>>->>>>>[-]<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>
+>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>]<<+<<<<<+<[>-]>[->>>
+>>-<<<<]>>>>>>>+<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[<-<<<<<<<<<<<<<<<<<<<[-<<
+<<<<<<<<<<<<<<<<<<+<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]<<<<<<<<<<<<<<<<<<<<[->>>>>>>
+>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>>>>>[-]]<[-<<<<<<
+<<<<<<<<[->>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>
+>>>>>>>>>>>>>>>>>>>>>>]>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]<<<<<<<[
+-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>
+>>>]<<<[-]<<<<<<<[-]]<[-<<<<<<<<[->>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>
+>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]<<<<<<<<[->>>>>>>>+<<<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>>>[-<<<<<<<<+>>>>>>
+>>]<<<[->>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>
+[-<<<+>>>]<]<[-]<<[-]>[-]>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>+<+<<<<]
+>>>>>[-<<<<<+>>>>>]<[>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>-<+<<<<<<<<<<<<<<<<<<<<[->>>
+>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>]>>>>>>>>>>>>>>>>>>
+>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+<<<[>>>>>>>>>>>>>>>>>>>>>>>>>>>>>-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]]<[-]]>>>>>>>>
+>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<[-<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>
+>>>>>>>+<<<<<<<<<<<<<<<<<<<<]<<<<<<<<<[->>>>>>>>>+<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>
+>>>>>>>>>>]+<[->>>+<+<<]>>>[-<<<+>>>]<[<-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>
++>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]>>>[-<<<
++>>>]>>>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<
+<<<<<<<<<<]<<<<<<<<<<[->>>>>>>>>>+<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+>>>+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>
+>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>[<<<<<<<<->>>->>>>>[-]<<+<<<<<+<
+[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->
+>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>]<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>>>+<<<[->
+>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[<-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+<[->>>>+>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]>>>>[-<<<<
++>>>>]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<+>>>>]>>>>>>>>>>[-]]<[-<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<[-<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]<
+<<<<<<<<[->>>>>>>>>+<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<+>>>>>
+>>]>>>>>>]<<<[-]<<<<<<<[-]]<[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>+>>>>>>>>
+>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]>>>>[-<<<<+>>>>]<<<[->>>+>
+>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]>>>[-<<<+>>>]>>>>>
+>>>>>>[-<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]<<<<<<<<<<[->
+>>>>>>>>>+<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]<[-]<<<<<<<<<<<<<<<<<<<<<<<<
+<<<<<<<<<<[-]>[-]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>
+>>>>>>>>>>>>>>>>>>>>>>>>]<<<<<<<<<<++++++++++++++>>>>>>]<<<[-]>[-]>[-]<<<<[-]]<<
+[->>>+<+<<]>>>[-<<<+>>>]<[<<<<<<<<<<<<<<<<<<<<<<<[->>>+>+<<<<]>>>[-<<<+>>>]>[-<<
+<<[->>>+>>+<<<<<]>>>[-<<<+>>>]>]>>++++++++<[->-[>+>>]>[+[-<+>]>+>>]<<<<<]>[-]>[-
+]>[->>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<]<<<<<<[->+>+<<]>[-<+>]>[-<<[->+>>+<<<]>[
+-<+>]>]>>++++++++<[->-[>+>>]>[+[-<+>]>+>>]<<<<<]>[-]>[-]>[->>>>>>>>>>>>>>>>>>+<<
+<<<<<<<<<<<<<<<<]>>>>>>>>>>>>>>>>>[->>>+<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>
+>>>>]>>>[-<<<+>>>]<<[->>+<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]>>[-<<+>>
+]<<<<<<<<<<<<<<<<<<<<<<+++++++++++++++++++++++++++++++++<[->-[>+>>]>[+[-<+>]>+>>
+]<<<<<]>[-]>[-]>[->>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>+<
+[->>>+<+<<]>>>[-<<<+>>>]<[<-<<<<<<<[-]>>>>>>>>[-]]<[->>>+<<<<<<<<<<<<<<<<<<<<<<<
+<<<<<<<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[>>>>>>>>>>>>>>>>>>>>>>>>>>>>-<<+<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<
+<<<<<<<+<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>
+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]<<<<<<<<<<<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>>>
+>>>-<<<<<<<<<<<<<<<<<<<<<<<<<[-]]<[-]]>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<[->>+>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]>
+>[-<<+>>]>>>>>>>>>>>>>>>>>>>>>>>>>>>>]<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>+>+<<<
+<]>>>[-<<<+>>>]>[-<<[->+>>+<<<]>[-<+>]>]>>++++<[->-[>+>>]>[+[-<+>]>+>>]<<<<<]>[-
+]>[-]>[->>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<]<<<<<<<[-]>[-]>>>>>>>>>>>>
+>>>>>>>>>>>>>>>>>>+<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[<-<+<<<<<<<<<<<<<<<<[-
+<<<<<<<<<<<<+>+>>>>>>>>>>>]<<<<<<<<<<<<[->>>>>>>>>>>>+<<<<<<<<<<<<]>[>>>>>>>>>>>
+>>>>>>>>>>>>>>>>-<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]
+]<[-<<<<<<<<<<<<<<<<<[-<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<]
+<<<<<<<<<<<[->>>>>>>>>>>+<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>]+<[->>>+<+<<]>
+>>[-<<<+>>>]<[<-<<[->>>>>>>>>>>>>+<<<<<<<<<+<<<<]>>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>
+>>>>>>>>>>>]<<<<<<<<<<<<<<<<<<<<<<<<<<<[-<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>
+>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]<<<<<<<<<<<<[->>>>>>>>>>>>+<<<<<<<<<<<<]>>>>>>>>>>
+>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->>>-<<]>[-
+>>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>[<<<<<<<<
+->>>->>>>>[-]<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->>>-<<]>[->>>>+<+<<<]
+>>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>]<<+<<<<<+<[>-]>[->>
+>>>-<<<<]>>>>>>>+<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[<-<<<<<<<<<<<<<<[->>>>>>
+>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>
+>>>>]>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]<<<<<<<<<<<[-<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>>>>>[-]]<[-<<<<<<<
+<<<<<<<<<<<<<<<<<<<<<[-<<<<<<<<<<<+<<<+>>>>>>>>>>>>>>]<<<<<<<<<<<[->>>>>>>>>>>+<
+<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>]<<<[-]<<<<<<<[-]]<[-<<<[->>>>+<<<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>[-<<<<+>>>>]<<<[->>
+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>[-<<<+>>>]<<<<
+<<<<<<<<<<<<<[-<<<<<<<<<<<<+<<+>>>>>>>>>>>>>>]<<<<<<<<<<<<[->>>>>>>>>>>>+<<<<<<<
+<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>]<[-]<<[-]>[-]<+<<<[->>>>+<<<<]<<<<<<<<<<<<<<<
+<<<<<<<<<<<<[-]>[-]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<[->>>>>>>>>>+<+<<<<<
+<<<<]>>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]<[<-<+<<[->>>>>>+<+<<<<<]>>>>>>[-<<<<<<+>
+>>>>>]<[<<<->>>[-]]<[-]]<[-<<<[->>>>+<<+<<]>>>>[-<<<<+>>>>]<]+<[->>>+<+<<]>>>[-<
+<<+>>>]<[<-<<<<<<<[->>>>>>>>>>>>>>>>>>+<<<<<<<<<+<<<<<<<<<]>>>>>>>>>>>>>>>>>>[-<
+<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]<<<<<<<<<<<<<[->>>>>>>>>>>>>+<<<<<<+<<<<<<<
+]>>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]<<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<
+<<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]
+]<<<[-]>[-]>[<<<<<<<<->>>->>>>>[-]<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[-
+>>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]
+>]<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>>>+<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[<-<
+<<<<<<<<<<<<<<<<<<[-<<<<<<<<<<<<<<<<<<<<+<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]<<<<<<<
+<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>
+>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>
+>>>>>>>>>[-]]<[-<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>
+>>>>>>>>>>>>>]<<<<<<<[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>
+>>>>>>>>>>>>>>>>>>]>>>>>>]<<<[-]<<<<<<<[-]]<[-<<<<<<<<[->>>>>>>>>+<<<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]<<
+<<<<<<[->>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]>>>
+>>>>>[-<<<<<<<<+>>>>>>>>]<<<[->>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>
+>>>>>>>>>>>>>>>>>>]>>>[-<<<+>>>]<]<[-]<<[-]>[-]>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+<<<<<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>-<+<<<
+<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>
+>>>]>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]<<<<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>>>>>>>>>>-<<<<<<<<<<<<<<<<<<<<<<<<
+<<<<<[-]]<[-]]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<[-<<<<<<<<<+
+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]<<<<<<<<<[->>>>>>>>>+<<<<<<<<
+<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]+<[->>>+<+<<]>>>[-<<<+>>>]<[<-<<<<<<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<<[->>>+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<
+<<<<<<<<<<<<<]>>>[-<<<+>>>]>>>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<]<<<<<<<<<<[->>>>>>>>>>+<<<<<<<<<<]>>>>>>>>>>>>>
+>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->>>-<<]>[->
+>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>[<<<<<<<<-
+>>>->>>>>[-]<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>
+>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>]<<+<<<<<+<[>-]>[->>>
+>>-<<<<]>>>>>>>+<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[<-<<<<<<<<<<<<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<<<<[->>>>+>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<
+<<<<<<<<<<<]>>>>[-<<<<+>>>>]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<+>>>>]>>>>>>>
+>>>[-]]<[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>
+>+<<<<<<<<<<<<<<<<<<]<<<<<<<<<[->>>>>>>>>+<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+>>>>>>>[-<<<<<<<+>>>>>>>]>>>>>>]<<<[-]<<<<<<<[-]]<[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+<<<<<<<[->>>>+>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]>>>>
+[-<<<<+>>>>]<<<[->>>+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+<<<]>>>[-<<<+>>>]>>>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<
+<<<<<<<<]<<<<<<<<<<[->>>>>>>>>>+<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]<[-]<<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]>[-]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>[-<<<<<<<<<<<<<<<<<<<
+<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]<<<<<<<<<<++++++++++++>>>>>>]<<<[
+-]>[-]>[-]<<<<[-]]<<[->>>+<+<<]>>>[-<<<+>>>]<[<<<<<<<<<<<<<<<<<<<<<<<[->>>+>+<<<
+<]>>>[-<<<+>>>]>[-<<<<[->>>+>>+<<<<<]>>>[-<<<+>>>]>]>>++++++++<[->-[>+>>]>[+[-<+
+>]>+>>]<<<<<]>[-]>[-]>[->>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<]<<<<<<[->+>+<<]>[-<+
+>]>[-<<[->+>>+<<<]>[-<+>]>]>>++++++++<[->-[>+>>]>[+[-<+>]>+>>]<<<<<]>[-]>[-]>[->
+>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]>>>>>>>>>>>>>>>>>[->>>+<<<<<<<<<<<<<<<<<<<<
+<<<+>>>>>>>>>>>>>>>>>>>>]>>>[-<<<+>>>]<<[->>+<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>
+>>>>>>>>>>]>>[-<<+>>]<<<<<<<<<<<<<<<<<<<<<<+++++++++++++++++++++++++++++++++<[->
+-[>+>>]>[+[-<+>]>+>>]<<<<<]>[-]>[-]>[->>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<]>>
+>>>>>>>>>>>>>>>>>>+<[->>>+<+<<]>>>[-<<<+>>>]<[<-<<<<<<<[-]>>>>>>>>[-]]<[->>>+<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[>>>>>>>>>>>>>>>
+>>>>>>>>>>>>>-<<+<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<+<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]<<<<<<<<<<<<<<<<<<<<<<<<<<<<[>>
+>>>>>>>>>>>>>>>>>>>>>>>-<<<<<<<<<<<<<<<<<<<<<<<<<[-]]<[-]]>>>>>>>>>>>>>>>>>>>>>>
+>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>+>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<]>>[-<<+>>]>>>>>>>>>>>>>>>>>>>>>>>>>>>>]<<<<<<<<<<<<<<<<<<<<<<
+<<<<<<<<<[->>>+>+<<<<]>>>[-<<<+>>>]>[-<<[->+>>+<<<]>[-<+>]>]>>++++<[->-[>+>>]>[+
+[-<+>]>+>>]<<<<<]>[-]>[-]>[->>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<]<<<<<<
+<[-]>[-]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[<-
+<+<<<<<<<<<<<<<<<<[-<<<<<<<<<<<<+>+>>>>>>>>>>>]<<<<<<<<<<<<[->>>>>>>>>>>>+<<<<<<
+<<<<<<]>[>>>>>>>>>>>>>>>>>>>>>>>>>>>-<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]]>>>>>>>>>>>>
+>>>>>>>>>>>>>>>>>[-]]<[-<<<<<<<<<<<<<<<<<[-<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>
+>>+<<<<<<<<<<<<<<<<]<<<<<<<<<<<[->>>>>>>>>>>+<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>
+>>>>>]+<[->>>+<+<<]>>>[-<<<+>>>]<[<-<<[->>>>>>>>>>>>>+<<<<<<<<<+<<<<]>>>>>>>>>>>
+>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]<<<<<<<<<<<<<<<<<<<<<<<<<<<[-<<<<<<<<<<<<+>>>>>>
+>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]<<<<<<<<<<<<[->>>>>>>>>>>>+<<<
+<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<
+<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]
+<<<[-]>[-]>[<<<<<<<<->>>->>>>>[-]<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->
+>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>
+]<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>>>+<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[<-<<
+<<<<<<<<<<<<[->>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>
+>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]<<<
+<<<<<<<<[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>
+>>>>>>[-]]<[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-<<<<<<<<<<<+<<<+>>>>>>>>>>>>>>]<<<<<<
+<<<<<[->>>>>>>>>>>+<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>]<<<[-]<<<<<<<[-
+]]<[-<<<[->>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>
+>[-<<<<+>>>>]<<<[->>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>
+>>]>>>[-<<<+>>>]<<<<<<<<<<<<<<<<<[-<<<<<<<<<<<<+<<+>>>>>>>>>>>>>>]<<<<<<<<<<<<[-
+>>>>>>>>>>>>+<<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>]<[-]<<[-]>[-]<+<<<[->>>>+
+<<<<]<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]>[-]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<
+[->>>>>>>>>>+<+<<<<<<<<<]>>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]<[<-<+<<[->>>>>>+<+<<
+<<<]>>>>>>[-<<<<<<+>>>>>>]<[<<<->>>[-]]<[-]]<[-<<<[->>>>+<<+<<]>>>>[-<<<<+>>>>]<
+]+<[->>>+<+<<]>>>[-<<<+>>>]<[<-<<<<<<<[->>>>>>>>>>>>>>>>>>+<<<<<<<<<+<<<<<<<<<]>
+>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]<<<<<<<<<<<<<[->>>>>>>>
+>>>>>+<<<<<<+<<<<<<<]>>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]<<<+<<<<<+<[>-]>
+[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<
++<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>[<<<<<<<<->>>->>>>>[-]<<+<<<<<+<[>-]>[->>>>>-<<<
+<]>>>>>+<<<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<
++>>>]<[-]]<<<[-]>[-]>]<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>>>+<<<[->>>>>+<+<<<<]>>>>
+>[-<<<<<+>>>>>]<[<-<<<<<<<<<<<<<<<<<<<[-<<<<<<<<<<<<<<<<<<<<+<<<<<+>>>>>>>>>>>>>
+>>>>>>>>>>>>]<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]>>>
+>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>
+>>>>>>>>>>>>>>>>>>]>>>>>>>>>>[-]]<[-<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>>>>>>>>>>
+[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]<<<<<<<[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
++>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>]<<<[-]<<<<<<<[-]]<[-<<<<<<<<[->>>>
+>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>>>>[-<<
+<<<<<<<+>>>>>>>>>]<<<<<<<<[->>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>
+>>>>>>>>>>>>>>>>]>>>>>>>>[-<<<<<<<<+>>>>>>>>]<<<[->>>+<<<<<<<<<<<<<<<<<<<<<<<<<<
+<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>[-<<<+>>>]<]<[-]<<[-]>[-]>>>>+<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[>>>>>>>>>>>>>>>>>
+>>>>>>>>>>>>>>-<+<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<
+<<<<<<<<<<<<<+>>>>>>>>>]>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>
+>>>>>>>>>>>>>]<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>>>>>>>>>>-<<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<[-]]<[-]]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<
+<<<<<<<<[-<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]<<<<<<<<<
+[->>>>>>>>>+<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]+<[->>>+<+<<]>>>[-<<<+>>>]<
+[<-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]>>>[-<<<+>>>]>>>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>
+>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<]<<<<<<<<<<[->>>>>>>>>>+<<<<
+<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<
++<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<
+<<[-]>[-]>[<<<<<<<<->>>->>>>>[-]<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->>
+>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>]
+<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>>>+<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[<-<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>+>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]>>>>[-<<<<+>>>>]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+>[-<<<<+>>>>]>>>>>>>>>>[-]]<[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-<<<<<<<<<+>>>>>>
+>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]<<<<<<<<<[->>>>>>>>>+<<<<<<<<<]>>>>>>>>
+>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<+>>>>>>>]>>>>>>]<<<[-]<<<<<<<[-]]<[-<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>+>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<
+<<<<<<<<<<<<<<<]>>>>[-<<<<+>>>>]<<<[->>>+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<<<<]>>>[-<<<+>>>]>>>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>
+>>>>>>>>>+<<<<<<<<<<<<<<<<<<]<<<<<<<<<<[->>>>>>>>>>+<<<<<<<<<<]>>>>>>>>>>>>>>>>>
+>>>>>>>>>>>>>]<[-]<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]>[-]>>>>>>>>>>>>>>>>>>>>>
+>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>[
+-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]<<<<<<<<<<---
+---------->>>>>>]<<<[-]>[-]>[-]<<<<[-]]<<[->>>+<+<<]>>>[-<<<+>>>]<[<<<<<<<<<<<<<
+<<<<<<<<<<[->>>+>+<<<<]>>>[-<<<+>>>]>[-<<<<[->>>+>>+<<<<<]>>>[-<<<+>>>]>]>>+++++
++++<[->-[>+>>]>[+[-<+>]>+>>]<<<<<]>[-]>[-]>[->>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<
+]<<<<<<[->+>+<<]>[-<+>]>[-<<[->+>>+<<<]>[-<+>]>]>>++++++++<[->-[>+>>]>[+[-<+>]>+
+>>]<<<<<]>[-]>[-]>[->>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]>>>>>>>>>>>>>>>>>[->>>
++<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]>>>[-<<<+>>>]<<[->>+<<<<<<<<<<<<<<
+<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]>>[-<<+>>]<<<<<<<<<<<<<<<<<<<<<<++++++++++++++++
++++++++++++++++++<[->-[>+>>]>[+[-<+>]>+>>]<<<<<]>[-]>[-]>[->>>>>>>>>>>>>>>>>>>+<
+<<<<<<<<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>+<[->>>+<+<<]>>>[-<<<+>>>]<[<-<<<<<<<[-]>
+>>>>>>>[-]]<[->>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>
+>>>]<[>>>>>>>>>>>>>>>>>>>>>>>>>>>>-<<+<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>
+>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<+<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>
+>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>>>>>>-<<<<<<<<<<<<<<<<<<<<<<<<<[-]]<[-]]>
+>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>+>>>>>>>>>>>>>>>>
+>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]>>[-<<+>>]>>>>>>>>>>>>>>>>>>>>>>>>>>>>]<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>+>+<<<<]>>>[-<<<+>>>]>[-<<[->+>>+<<<]>[-<+>]>
+]>>++++<[->-[>+>>]>[+[-<+>]>+>>]<<<<<]>[-]>[-]>[->>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<
+<<<<<<<<<<<<<<]<<<<<<<[-]>[-]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<[->>>>>+<+<<<<]>>
+>>>[-<<<<<+>>>>>]<[<-<+<<<<<<<<<<<<<<<<[-<<<<<<<<<<<<+>+>>>>>>>>>>>]<<<<<<<<<<<<
+[->>>>>>>>>>>>+<<<<<<<<<<<<]>[>>>>>>>>>>>>>>>>>>>>>>>>>>>-<<<<<<<<<<<<<<<<<<<<<<
+<<<<<[-]]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]]<[-<<<<<<<<<<<<<<<<<[-<<<<<<<<<<<+>>>>
+>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<]<<<<<<<<<<<[->>>>>>>>>>>+<<<<<<<<<<<]>>
+>>>>>>>>>>>>>>>>>>>>>>>>>>]+<[->>>+<+<<]>>>[-<<<+>>>]<[<-<<[->>>>>>>>>>>>>+<<<<<
+<<<<+<<<<]>>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]<<<<<<<<<<<<<<<<<<<<<<<<<<<
+[-<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]<<<<<<<<<
+<<<[->>>>>>>>>>>>+<<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<+<[>-]>
+[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<
++<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>[<<<<<<<<->>>->>>>>[-]<<+<<<<<+<[>-]>[->>>>>-<<<
+<]>>>>>+<<<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<
++>>>]<[-]]<<<[-]>[-]>]<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>>>+<<<[->>>>>+<+<<<<]>>>>
+>[-<<<<<+>>>>>]<[<-<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<
++>>>>>>>>>>>>>>>>]<<<<<<<<<<<[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>
+>>>>>>>>>>>>>>>>]>>>>>>>>>>[-]]<[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-<<<<<<<<<<<+<<<+
+>>>>>>>>>>>>>>]<<<<<<<<<<<[->>>>>>>>>>>+<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>
+>>>>>]<<<[-]<<<<<<<[-]]<[-<<<[->>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>
+>>>>>>>>>>>>>>>>>]>>>>[-<<<<+>>>>]<<<[->>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>
+>>>>>>>>>>>>>>>>>>>>>>>]>>>[-<<<+>>>]<<<<<<<<<<<<<<<<<[-<<<<<<<<<<<<+<<+>>>>>>>>
+>>>>>>]<<<<<<<<<<<<[->>>>>>>>>>>>+<<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>]<[-]
+<<[-]>[-]<+<<<[->>>>+<<<<]<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]>[-]>>>>>>>>>>>>>>>>>>>>
+>>>>>>>>>>>>+<<<<<<<<[->>>>>>>>>>+<+<<<<<<<<<]>>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]
+<[<-<+<<[->>>>>>+<+<<<<<]>>>>>>[-<<<<<<+>>>>>>]<[<<<->>>[-]]<[-]]<[-<<<[->>>>+<<
++<<]>>>>[-<<<<+>>>>]<]+<[->>>+<+<<]>>>[-<<<+>>>]<[<-<<<<<<<[->>>>>>>>>>>>>>>>>>+
+<<<<<<<<<+<<<<<<<<<]>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]<<
+<<<<<<<<<<<[->>>>>>>>>>>>>+<<<<<<+<<<<<<<]>>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>
+>>>>]<<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>[-<
+<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>[<<<<<<<<->>>->>>>>[-]<<+<<
+<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[
+<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>]<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>>>+<
+<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[<-<<<<<<<<<<<<<<<<<<<[-<<<<<<<<<<<<<<<<<<<
+<+<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+<<<
+<<<<<<<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>>>>>[-]]<[-<<<<<<<<<<<<<<[->>>>>>>
+>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>
+>>>>>]>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]<<<<<<<[-<<<<<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>]<<<[-]<<<<<<<
+[-]]<[-<<<<<<<<[->>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>
+>>>>>>>]>>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]<<<<<<<<[->>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<
+<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>>>[-<<<<<<<<+>>>>>>>>]<<<[->>>+<<<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>[-<<<+>>>]<]<[-]<
+<[-]>[-]>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>
+>]<[>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>-<+<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>
+>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>]>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<
+<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>
+>>>>>>>>>>>>>>>>-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]]<[-]]>>>>>>>>>>>>>>>>>>>>>>>>>
+>>>>>>[-<<<<<<<<<<<<<<<<<<<<<[-<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<
+<<<<<<<<<<<]<<<<<<<<<[->>>>>>>>>+<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]+<[->>
+>+<+<<]>>>[-<<<+>>>]<[<-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>+>>>>>>>>>>>>>>>>
+>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]>>>[-<<<+>>>]>>>>>>>>>>>[
+-<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<]<<<<<<
+<<<<[->>>>>>>>>>+<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<+<[>-]>[
+->>>>>-<<<<]>>>>>+<<<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<+
+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>[<<<<<<<<->>>->>>>>[-]<<+<<<<<+<[>-]>[->>>>>-<<<<
+]>>>>>+<<<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+
+>>>]<[-]]<<<[-]>[-]>]<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>>>+<<<[->>>>>+<+<<<<]>>>>>
+[-<<<<<+>>>>>]<[<-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>+>>>>>>>>>
+>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]>>>>[-<<<<+>>>>]>>>>>>>>>>>
+>>>>>>>>>>>>>>>>>>>>>>[-<<<<+>>>>]>>>>>>>>>>[-]]<[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+<<<[-<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]<<<<<<<<<[->>>>>>>
+>>+<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<+>>>>>>>]>>>>>>]<<<[-]<
+<<<<<<[-]]<[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>+>>>>>>>>>>>>>>>>>>>>>>>>>
+>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]>>>>[-<<<<+>>>>]<<<[->>>+>>>>>>>>>>>>>>>>>>
+>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]>>>[-<<<+>>>]>>>>>>>>>>>[-<<<<<<<<<
+<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]<<<<<<<<<<[->>>>>>>>>>+<<<<<<<
+<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]<[-]<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]>[-]
+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>
+>>>>>>>>>>>>>>>>>>]>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>
+>>>>>>>]<<<<<<<<<<++++++++++++++++>>>>>>]<<<[-]>[-]>[-]<<<<[-]]<<[->>>+<+<<]>>>[
+-<<<+>>>]<[<<<<<<<<<<<<<<<<<<<<<<<[->>>+>+<<<<]>>>[-<<<+>>>]>[-<<<<[->>>+>>+<<<<
+<]>>>[-<<<+>>>]>]>>++++++++<[->-[>+>>]>[+[-<+>]>+>>]<<<<<]>[-]>[-]>[->>>>>>>>>>>
+>>>>>>+<<<<<<<<<<<<<<<<<]<<<<<<[->+>+<<]>[-<+>]>[-<<[->+>>+<<<]>[-<+>]>]>>++++++
+++<[->-[>+>>]>[+[-<+>]>+>>]<<<<<]>[-]>[-]>[->>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<
+<]>>>>>>>>>>>>>>>>>[->>>+<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>]>>>[-<<<+>
+>>]<<[->>+<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>]>>[-<<+>>]<<<<<<<<<<<<<<
+<<<<<<<<+++++++++++++++++++++++++++++++++<[->-[>+>>]>[+[-<+>]>+>>]<<<<<]>[-]>[-]
+>[->>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>+<[->>>+<+<<]>>>[
+-<<<+>>>]<[<-<<<<<<<[-]>>>>>>>>[-]]<[->>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>
+>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[>>>>>>>>>>>>>>>>>>>>>>>>>>>>-<<+<<<<<<<<<<<<<<<<<<
+<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<+<<<]>>>
+>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>
+>>>>>>>>>>>>>]<<<<<<<<<<<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>>>>>>-<<<<<<<<<<<
+<<<<<<<<<<<<<<[-]]<[-]]>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<
+<<<[->>+>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<]>>[-<<+>>]>>>>>>
+>>>>>>>>>>>>>>>>>>>>>>]<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>+>+<<<<]>>>[-<<<+>>>]
+>[-<<[->+>>+<<<]>[-<+>]>]>>++++<[->-[>+>>]>[+[-<+>]>+>>]<<<<<]>[-]>[-]>[->>>>>>>
+>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<]<<<<<<<[-]>[-]>>>>>>>>>>>>>>>>>>>>>>>>>>>
+>>>+<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[<-<+<<<<<<<<<<<<<<<<[-<<<<<<<<<<<<+>+
+>>>>>>>>>>>]<<<<<<<<<<<<[->>>>>>>>>>>>+<<<<<<<<<<<<]>[>>>>>>>>>>>>>>>>>>>>>>>>>>
+>-<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-]]<[-<<<<<<<<<<<
+<<<<<<[-<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<]<<<<<<<<<<<[->>
+>>>>>>>>>+<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>]+<[->>>+<+<<]>>>[-<<<+>>>]<[<
+-<<[->>>>>>>>>>>>>+<<<<<<<<<+<<<<]>>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]<<<
+<<<<<<<<<<<<<<<<<<<<<<<<[-<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<
+<<<<<<<<<<<<<<]<<<<<<<<<<<<[->>>>>>>>>>>>+<<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>
+>>>>>>>>>>>+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>
+[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>[<<<<<<<<->>>->>>>>[-]<<
++<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>
+]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>]<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>>
+>+<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[<-<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>+<<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>>>>>
+>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]<<<<<<<<<<<[-<<<<<<<<<<<<<<<<<<<<<<<<<
+<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>>>>>[-]]<[-<<<<<<<<<<<<<<<<<<<<<<
+<<<<<<[-<<<<<<<<<<<+<<<+>>>>>>>>>>>>>>]<<<<<<<<<<<[->>>>>>>>>>>+<<<<<<<<<<<]>>>>
+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>
+>>>>>>>>>>>>>>>>>>>>>>]>>>>>>]<<<[-]<<<<<<<[-]]<[-<<<[->>>>+<<<<<<<<<<<<<<<<<<<<
+<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>[-<<<<+>>>>]<<<[->>>+<<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>[-<<<+>>>]<<<<<<<<<<<<<<<<<[-
+<<<<<<<<<<<<+<<+>>>>>>>>>>>>>>]<<<<<<<<<<<<[->>>>>>>>>>>>+<<<<<<<<<<<<]>>>>>>>>>
+>>>>>>>>>>>>>>>>>>>]<[-]<<[-]>[-]<+<<<[->>>>+<<<<]<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]
+>[-]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<[->>>>>>>>>>+<+<<<<<<<<<]>>>>>>>>>>
+[-<<<<<<<<<<+>>>>>>>>>>]<[<-<+<<[->>>>>>+<+<<<<<]>>>>>>[-<<<<<<+>>>>>>]<[<<<->>>
+[-]]<[-]]<[-<<<[->>>>+<<+<<]>>>>[-<<<<+>>>>]<]+<[->>>+<+<<]>>>[-<<<+>>>]<[<-<<<<
+<<<[->>>>>>>>>>>>>>>>>>+<<<<<<<<<+<<<<<<<<<]>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<
+<<+>>>>>>>>>>>>>>>>>>]<<<<<<<<<<<<<[->>>>>>>>>>>>>+<<<<<<+<<<<<<<]>>>>>>>>>>>>>[
+-<<<<<<<<<<<<<+>>>>>>>>>>>>>]<<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->>>-
+<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>[<<
+<<<<<<->>>->>>>>[-]<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->>>-<<]>[->>>>+
+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>]<<+<<<<<+<[>-
+]>[->>>>>-<<<<]>>>>>>>+<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[<-<<<<<<<<<<<<<<<<
+<<<[-<<<<<<<<<<<<<<<<<<<<+<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]<<<<<<<<<<<<<<<<<<<<[-
+>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>>>>>[-]]<[
+-<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>
+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]<
+<<<<<<[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+>>>]>>>>>>]<<<[-]<<<<<<<[-]]<[-<<<<<<<<[->>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]<<<<<<<<[->>>>>>>
+>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>>>[-<<<<<<<<
++>>>>>>>>]<<<[->>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>
+>>>]>>>[-<<<+>>>]<]<[-]<<[-]>[-]>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>+
+<+<<<<]>>>>>[-<<<<<+>>>>>]<[>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>-<+<<<<<<<<<<<<<<<<<<
+<<[->>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>]>>>>>>>>>>>
+>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>]<<<<<<<<<<<<<<<<<<<<<
+<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>>>>>>>>>>-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]]<[-]]>
+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<[-<<<<<<<<<+>>>>>>>>>>>>>>>
+>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]<<<<<<<<<[->>>>>>>>>+<<<<<<<<<]>>>>>>>>>>>>>
+>>>>>>>>>>>>>>>>>]+<[->>>+<+<<]>>>[-<<<+>>>]<[<-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+<<[->>>+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]>
+>>[-<<<+>>>]>>>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<
+<<<<<<<<<<<<<<<<<]<<<<<<<<<<[->>>>>>>>>>+<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+>>>>>>>>>>+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>[
+-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>[<<<<<<<<->>>->>>>>[-]<<+
+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]
+<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>]<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>>>
++<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[<-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+<<<<<<<<[->>>>+>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]>>>
+>[-<<<<+>>>>]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<+>>>>]>>>>>>>>>>[-]]<[-<<<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<[-<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<
+<<<<<]<<<<<<<<<[->>>>>>>>>+<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<
+<+>>>>>>>]>>>>>>]<<<[-]<<<<<<<[-]]<[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>+>
+>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]>>>>[-<<<<+>>>>]<<<
+[->>>+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]>>>[-<<<+>>
+>]>>>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]<<<<<<
+<<<<[->>>>>>>>>>+<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]<[-]<<<<<<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<<[-]>[-]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<
+<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>
+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]<<<<<<<<<<------------------>>>>>>]<<<[-]>[-]>[-
+]<<<<[-]]<<[->>>+<+<<]>>>[-<<<+>>>]<[<<<<<<<<<<<<<<<<<<<<<<<[->>>+>+<<<<]>>>[-<<
+<+>>>]>[-<<<<[->>>+>>+<<<<<]>>>[-<<<+>>>]>]>>++++++++<[->-[>+>>]>[+[-<+>]>+>>]<<
+<<<]>[-]>[-]>[->>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<]<<<<<<[->+>+<<]>[-<+>]>[-<<[-
+>+>>+<<<]>[-<+>]>]>>++++++++<[->-[>+>>]>[+[-<+>]>+>>]<<<<<]>[-]>[-]>[->>>>>>>>>>
+>>>>>>>>+<<<<<<<<<<<<<<<<<<]>>>>>>>>>>>>>>>>>[->>>+<<<<<<<<<<<<<<<<<<<<<<<+>>>>>
+>>>>>>>>>>>>>>>]>>>[-<<<+>>>]<<[->>+<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>
+>]>>[-<<+>>]<<<<<<<<<<<<<<<<<<<<<<+++++++++++++++++++++++++++++++++<[->-[>+>>]>[
++[-<+>]>+>>]<<<<<]>[-]>[-]>[->>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<]>>>>>>>>>>>
+>>>>>>>>>+<[->>>+<+<<]>>>[-<<<+>>>]<[<-<<<<<<<[-]>>>>>>>>[-]]<[->>>+<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[>>>>>>>>>>>>>>>>>>>>>>>>
+>>>>-<<+<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<+<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<
+<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]<<<<<<<<<<<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>
+>>>>>>>>>>>>>>-<<<<<<<<<<<<<<<<<<<<<<<<<[-]]<[-]]>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>+>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<
+<<<<<<<<<]>>[-<<+>>]>>>>>>>>>>>>>>>>>>>>>>>>>>>>]<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+[->>>+>+<<<<]>>>[-<<<+>>>]>[-<<[->+>>+<<<]>[-<+>]>]>>++++<[->-[>+>>]>[+[-<+>]>+>
+>]<<<<<]>[-]>[-]>[->>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<]<<<<<<<[-]>[-]>
+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[<-<+<<<<<<<
+<<<<<<<<<[-<<<<<<<<<<<<+>+>>>>>>>>>>>]<<<<<<<<<<<<[->>>>>>>>>>>>+<<<<<<<<<<<<]>[
+>>>>>>>>>>>>>>>>>>>>>>>>>>>-<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]]>>>>>>>>>>>>>>>>>>>>>
+>>>>>>>>[-]]<[-<<<<<<<<<<<<<<<<<[-<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<
+<<<<<<<<<<]<<<<<<<<<<<[->>>>>>>>>>>+<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>]+<[
+->>>+<+<<]>>>[-<<<+>>>]<[<-<<[->>>>>>>>>>>>>+<<<<<<<<<+<<<<]>>>>>>>>>>>>>[-<<<<<
+<<<<<<<<+>>>>>>>>>>>>>]<<<<<<<<<<<<<<<<<<<<<<<<<<<[-<<<<<<<<<<<<+>>>>>>>>>>>>>>>
+>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]<<<<<<<<<<<<[->>>>>>>>>>>>+<<<<<<<<<<<<
+]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[
+->>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-
+]>[<<<<<<<<->>>->>>>>[-]<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->>>-<<]>[-
+>>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>]<<+<<<<<
++<[>-]>[->>>>>-<<<<]>>>>>>>+<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[<-<<<<<<<<<<<
+<<<[->>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>
+>>>>>>>>>>>>>>>]>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]<<<<<<<<<<<[
+-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>>>>>[-]
+]<[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-<<<<<<<<<<<+<<<+>>>>>>>>>>>>>>]<<<<<<<<<<<[->>
+>>>>>>>>>+<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<
+<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>]<<<[-]<<<<<<<[-]]<[-<<<[
+->>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>[-<<<<+>
+>>>]<<<[->>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>[-<
+<<+>>>]<<<<<<<<<<<<<<<<<[-<<<<<<<<<<<<+<<+>>>>>>>>>>>>>>]<<<<<<<<<<<<[->>>>>>>>>
+>>>+<<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>]<[-]<<[-]>[-]<+<<<[->>>>+<<<<]<<<<
+<<<<<<<<<<<<<<<<<<<<<<<[-]>[-]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<[->>>>>>>
+>>>+<+<<<<<<<<<]>>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]<[<-<+<<[->>>>>>+<+<<<<<]>>>>>
+>[-<<<<<<+>>>>>>]<[<<<->>>[-]]<[-]]<[-<<<[->>>>+<<+<<]>>>>[-<<<<+>>>>]<]+<[->>>+
+<+<<]>>>[-<<<+>>>]<[<-<<<<<<<[->>>>>>>>>>>>>>>>>>+<<<<<<<<<+<<<<<<<<<]>>>>>>>>>>
+>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]<<<<<<<<<<<<<[->>>>>>>>>>>>>+<<<
+<<<+<<<<<<<]>>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]<<<+<<<<<+<[>-]>[->>>>>-<
+<<<]>>>>>+<<<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<
+<<+>>>]<[-]]<<<[-]>[-]>[<<<<<<<<->>>->>>>>[-]<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<
+<<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]
+]<<<[-]>[-]>]<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>>>+<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+
+>>>>>]<[<-<<<<<<<<<<<<<<<<<<<[-<<<<<<<<<<<<<<<<<<<<+<<<<<+>>>>>>>>>>>>>>>>>>>>>>
+>>>]<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]>>>>>>>>>>>>
+>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>
+>>>>>>>>>]>>>>>>>>>>[-]]<[-<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>>>>>>>>>>[-<<<<<<<
+<<<<<<<<+>>>>>>>>>>>>>>>]<<<<<<<[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>
+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>]<<<[-]<<<<<<<[-]]<[-<<<<<<<<[->>>>>>>>>+<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>>>>[-<<<<<<<<<+>
+>>>>>>>>]<<<<<<<<[->>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>
+>>>>>>>]>>>>>>>>[-<<<<<<<<+>>>>>>>>]<<<[->>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>
+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>[-<<<+>>>]<]<[-]<<[-]>[-]>>>>+<<<<<<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[>>>>>>>>>>>>>>>>>>>>>>>>>>
+>>>>>-<+<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<
+<<<<+>>>>>>>>>]>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>
+>>>>]<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>>>>>>>>>>-<<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<[-]]<[-]]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<[
+-<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]<<<<<<<<<[->>>>>>>
+>>+<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]+<[->>>+<+<<]>>>[-<<<+>>>]<[<-<<<<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<<<<<]>>>[-<<<+>>>]>>>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>>>>>>>>>
+>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<]<<<<<<<<<<[->>>>>>>>>>+<<<<<<<<<<]>>
+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[-
+>>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]
+>[<<<<<<<<->>>->>>>>[-]<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->>>-<<]>[->
+>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>]<<+<<<<<+
+<[>-]>[->>>>>-<<<<]>>>>>>>+<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[<-<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>+>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<<<]>>>>[-<<<<+>>>>]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<+>
+>>>]>>>>>>>>>>[-]]<[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-<<<<<<<<<+>>>>>>>>>>>>>>>
+>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]<<<<<<<<<[->>>>>>>>>+<<<<<<<<<]>>>>>>>>>>>>>>>>>
+>>>>>>>>>>>>>>>>>>[-<<<<<<<+>>>>>>>]>>>>>>]<<<[-]<<<<<<<[-]]<[-<<<<<<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<[->>>>+>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<
+<<<<<<]>>>>[-<<<<+>>>>]<<<[->>>+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<
+<<<<<<<<<<<<<<]>>>[-<<<+>>>]>>>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>
++<<<<<<<<<<<<<<<<<<]<<<<<<<<<<[->>>>>>>>>>+<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>
+>>>>]<[-]<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]>[-]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>[-<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]<<<<<<<<<<->>>>>>]<<<[
+-]>[-]>[-]<<<<[-]]<<[->>>+<+<<]>>>[-<<<+>>>]<[<<<<<<<<<<<<<<<<<<<<<<<[->>>+>+<<<
+<]>>>[-<<<+>>>]>[-<<<<[->>>+>>+<<<<<]>>>[-<<<+>>>]>]>>++++++++<[->-[>+>>]>[+[-<+
+>]>+>>]<<<<<]>[-]>[-]>[->>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<]<<<<<<[->+>+<<]>[-<+
+>]>[-<<[->+>>+<<<]>[-<+>]>]>>++++++++<[->-[>+>>]>[+[-<+>]>+>>]<<<<<]>[-]>[-]>[->
+>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]>>>>>>>>>>>>>>>>>[->>>+<<<<<<<<<<<<<<<<<<<<
+<<<+>>>>>>>>>>>>>>>>>>>>]>>>[-<<<+>>>]<<[->>+<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>
+>>>>>>>>>>]>>[-<<+>>]<<<<<<<<<<<<<<<<<<<<<<+++++++++++++++++++++++++++++++++<[->
+-[>+>>]>[+[-<+>]>+>>]<<<<<]>[-]>[-]>[->>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<]>>
+>>>>>>>>>>>>>>>>>>+<[->>>+<+<<]>>>[-<<<+>>>]<[<-<<<<<<<[-]>>>>>>>>[-]]<[->>>+<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[>>>>>>>>>>>>>>>
+>>>>>>>>>>>>>-<<+<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<+<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]<<<<<<<<<<<<<<<<<<<<<<<<<<<<[>>
+>>>>>>>>>>>>>>>>>>>>>>>-<<<<<<<<<<<<<<<<<<<<<<<<<[-]]<[-]]>>>>>>>>>>>>>>>>>>>>>>
+>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>+>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<]>>[-<<+>>]>>>>>>>>>>>>>>>>>>>>>>>>>>>>]<<<<<<<<<<<<<<<<<<<<<<
+<<<<<<<<<[->>>+>+<<<<]>>>[-<<<+>>>]>[-<<[->+>>+<<<]>[-<+>]>]>>++++<[->-[>+>>]>[+
+[-<+>]>+>>]<<<<<]>[-]>[-]>[->>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<]<<<<<<
+<[-]>[-]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[<-
+<+<<<<<<<<<<<<<<<<[-<<<<<<<<<<<<+>+>>>>>>>>>>>]<<<<<<<<<<<<[->>>>>>>>>>>>+<<<<<<
+<<<<<<]>[>>>>>>>>>>>>>>>>>>>>>>>>>>>-<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]]>>>>>>>>>>>>
+>>>>>>>>>>>>>>>>>[-]]<[-<<<<<<<<<<<<<<<<<[-<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>
+>>+<<<<<<<<<<<<<<<<]<<<<<<<<<<<[->>>>>>>>>>>+<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>
+>>>>>]+<[->>>+<+<<]>>>[-<<<+>>>]<[<-<<[->>>>>>>>>>>>>+<<<<<<<<<+<<<<]>>>>>>>>>>>
+>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]<<<<<<<<<<<<<<<<<<<<<<<<<<<[-<<<<<<<<<<<<+>>>>>>
+>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<]<<<<<<<<<<<<[->>>>>>>>>>>>+<<<
+<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<
+<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]
+<<<[-]>[-]>[<<<<<<<<->>>->>>>>[-]<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->
+>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>
+]<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>>>+<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[<-<<
+<<<<<<<<<<<<[->>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>
+>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>]<<<
+<<<<<<<<[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>
+>>>>>>[-]]<[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-<<<<<<<<<<<+<<<+>>>>>>>>>>>>>>]<<<<<<
+<<<<<[->>>>>>>>>>>+<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>]<<<[-]<<<<<<<[-
+]]<[-<<<[->>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>
+>[-<<<<+>>>>]<<<[->>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>
+>>]>>>[-<<<+>>>]<<<<<<<<<<<<<<<<<[-<<<<<<<<<<<<+<<+>>>>>>>>>>>>>>]<<<<<<<<<<<<[-
+>>>>>>>>>>>>+<<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>]<[-]<<[-]>[-]<+<<<[->>>>+
+<<<<]<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]>[-]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<
+[->>>>>>>>>>+<+<<<<<<<<<]>>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>]<[<-<+<<[->>>>>>+<+<<
+<<<]>>>>>>[-<<<<<<+>>>>>>]<[<<<->>>[-]]<[-]]<[-<<<[->>>>+<<+<<]>>>>[-<<<<+>>>>]<
+]+<[->>>+<+<<]>>>[-<<<+>>>]<[<-<<<<<<<[->>>>>>>>>>>>>>>>>>+<<<<<<<<<+<<<<<<<<<]>
+>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>]<<<<<<<<<<<<<[->>>>>>>>
+>>>>>+<<<<<<+<<<<<<<]>>>>>>>>>>>>>[-<<<<<<<<<<<<<+>>>>>>>>>>>>>]<<<+<<<<<+<[>-]>
+[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<
++<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>[<<<<<<<<->>>->>>>>[-]<<+<<<<<+<[>-]>[->>>>>-<<<
+<]>>>>>+<<<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<
++>>>]<[-]]<<<[-]>[-]>]<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>>>+<<<[->>>>>+<+<<<<]>>>>
+>[-<<<<<+>>>>>]<[<-<<<<<<<<<<<<<<<<<<<[-<<<<<<<<<<<<<<<<<<<<+<<<<<+>>>>>>>>>>>>>
+>>>>>>>>>>>>]<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]>>>
+>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>
+>>>>>>>>>>>>>>>>>>]>>>>>>>>>>[-]]<[-<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+<<<<<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>>>>>>>>>>
+[-<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>]<<<<<<<[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
++>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>]<<<[-]<<<<<<<[-]]<[-<<<<<<<<[->>>>
+>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>]>>>>>>>>>[-<<
+<<<<<<<+>>>>>>>>>]<<<<<<<<[->>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>
+>>>>>>>>>>>>>>>>]>>>>>>>>[-<<<<<<<<+>>>>>>>>]<<<[->>>+<<<<<<<<<<<<<<<<<<<<<<<<<<
+<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>>>[-<<<+>>>]<]<[-]<<[-]>[-]>>>>+<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[>>>>>>>>>>>>>>>>>
+>>>>>>>>>>>>>>-<+<<<<<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<
+<<<<<<<<<<<<<+>>>>>>>>>]>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>
+>>>>>>>>>>>>>]<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[>>>>>>>>>>>>>>>>>>>>>>>>>>>>>-<<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<[-]]<[-]]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<
+<<<<<<<<[-<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<]<<<<<<<<<
+[->>>>>>>>>+<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]+<[->>>+<+<<]>>>[-<<<+>>>]<
+[<-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]>>>[-<<<+>>>]>>>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>
+>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<<<<<<<<]<<<<<<<<<<[->>>>>>>>>>+<<<<
+<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<
++<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<
+<<[-]>[-]>[<<<<<<<<->>>->>>>>[-]<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->>
+>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>]
+<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>>>+<<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[<-<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>+>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<]>>>>[-<<<<+>>>>]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+>[-<<<<+>>>>]>>>>>>>>>>[-]]<[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-<<<<<<<<<+>>>>>>
+>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<<]<<<<<<<<<[->>>>>>>>>+<<<<<<<<<]>>>>>>>>
+>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<+>>>>>>>]>>>>>>]<<<[-]<<<<<<<[-]]<[-<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<<<<<<<<[->>>>+>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<<
+<<<<<<<<<<<<<<<]>>>>[-<<<<+>>>>]<<<[->>>+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<
+<<<<<<<<<<<<<<<<<<<<<<<]>>>[-<<<+>>>]>>>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>
+>>>>>>>>>+<<<<<<<<<<<<<<<<<<]<<<<<<<<<<[->>>>>>>>>>+<<<<<<<<<<]>>>>>>>>>>>>>>>>>
+>>>>>>>>>>>>>]<[-]<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<[-]>[-]>>>>>>>>>>>>>>>>>>>>>
+>>>>>>>>>>[-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]>[
+-<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>]<<<<<<<<<<---
+---->>>>>>]<<<[-]>[-]>[-]<<<<[-]]<.>>>>+<<<<<<<<<<<<<[-<<<<<<<<<<+>+>>>>>>>>>]<<
+<<<<<<<<[->>>>>>>>>>+<<<<<<<<<<]>[>>>>>>>>>>>>>>>>>>>>>>-<+<<<<<<<<[->>>>>>>>>>>
++<+<<<<<<<<<<]>>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]<[<<->>[-]]<<<<<<<<<<<<<<<<<<
+<<<<<[-]]>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<<<[->>>>>>>>>>+<<+<<<<<<<<]>>>>>>>>>>[-<
+<<<<<<<<<+>>>>>>>>>>]<]+<[->>>+<+<<]>>>[-<<<+>>>]<[<-<<<<<<<<<<<<[-<<<<<<<<<<+>>
+>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<]<<<<<<<<<<[->>>>>>>>>>+<<<<<<<<<<]>>>>>>>>
+>>>>>>[-<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<<]<<<<<<<<<<<<<<[
+->>>>>>>>>>>>>>+<<<<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<+<[>-]>[->>>>
+>-<<<<]>>>>>+<<<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>
+[-<<<+>>>]<[-]]<<<[-]>[-]>[<<<<<<<<->>>->>>>>[-]<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>
+>+<<<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<
+[-]]<<<[-]>[-]>]<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>>>+<<<[->>>>>+<+<<<<]>>>>>[-<<<
+<<+>>>>>]<[<-<<<<<<<<<<<<<<<<<<<<<<<<[-<<<<<<<<<+>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<]
+<<<<<<<<<[->>>>>>>>>+<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<+>>>>]>>>>>>>>>>[-]
+]<[-<<<<<<<<<<<<<<<<<<<<[-<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>+<<<<<<]<<<<<<<<<<<<<
+[->>>>>>>>>>>>>+<<<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<+>>>>>>>]>>>>>
+>]<<<[-]<<<<<<<[-]]<[-<<<<<<<<<<<<<[-<<<<<<<<<+>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<]<<
+<<<<<<<[->>>>>>>>>+<<<<<<<<<]>>>>>>>>>>[-<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>+<<<<<<<
+<<<]<<<<<<<<<<[->>>>>>>>>>+<<<<<<<<<<]>>>>>>>>>>>>>>[->>>>>>>>>+<<<+<<<<<<]>>>>>
+>>>>[-<<<<<<<<<+>>>>>>>>>]<]<[-]<<<<<<<<<<<<[-]>[-]>>>>>>>>>[-<<<<<<<<<<+>>>>>>>
+>>>]>[-<<<<<<<<<<+>>>>>>>>>>]<<<<<<<<<<<<]>>>>>>>>>>[-]++++++++++.>>>>+<<<<<<<<<
+<<[-<<<<<<<<<<<<+>+>>>>>>>>>>>]<<<<<<<<<<<<[->>>>>>>>>>>>+<<<<<<<<<<<<]>[>>>>>>>
+>>>>>>>>>>>>>>>-<+<<<<<<[->>>>>>>>>+<+<<<<<<<<]>>>>>>>>>[-<<<<<<<<<+>>>>>>>>>]<[
+<<->>[-]]<<<<<<<<<<<<<<<<<<<<<<<[-]]>>>>>>>>>>>>>>>>>>>>>>[-<<<<<<<[->>>>>>>>+<<
++<<<<<<]>>>>>>>>[-<<<<<<<<+>>>>>>>>]<]+<[->>>+<+<<]>>>[-<<<+>>>]<[<-<<<<<<<<<<[-
+<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<<<<<<<]<<<<<<<<<<<<[->>>>>>>>>>>>+<<
+<<<<<<<<<<]>>>>>>>>>>>>>>>>[-<<<<<<<<<<<<<<<<+>>>>>>>>>>>>>>>>>>>>>>>>>>>+<<<<<<
+<<<<<]<<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>>>>>
+>>>>>>>>+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>[-<
+<<<+>>>>]<[<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>[<<<<<<<<->>>->>>>>[-]<<+<<
+<<<+<[>-]>[->>>>>-<<<<]>>>>>+<<<+<[>-]>[->>>-<<]>[->>>>+<+<<<]>>>>[-<<<<+>>>>]<[
+<<[->>>+<<+<]>>>[-<<<+>>>]<[-]]<<<[-]>[-]>]<<+<<<<<+<[>-]>[->>>>>-<<<<]>>>>>>>+<
+<<[->>>>>+<+<<<<]>>>>>[-<<<<<+>>>>>]<[<-<<<<<<<<<<<<<<<<<<<<<<[-<<<<<<<<<<<+>>>>
+>>>>>>>>>>>>>>>+<<<<<<<<]<<<<<<<<<<<[->>>>>>>>>>>+<<<<<<<<<<<]>>>>>>>>>>>>>>>>>>
+>>>>>>[-<<<<+>>>>]>>>>>>>>>>[-]]<[-<<<<<<<<<<<<<<<<<<[-<<<<<<<<<<<<<<<+>>>>>>>>>
+>>>>>>>>>>+<<<<]<<<<<<<<<<<<<<<[->>>>>>>>>>>>>>>+<<<<<<<<<<<<<<<]>>>>>>>>>>>>>>>
+>>>>>>>>>>>>[-<<<<<<<+>>>>>>>]>>>>>>]<<<[-]<<<<<<<[-]]<[-<<<<<<<<<<<[-<<<<<<<<<<
+<+>>>>>>>>>>>>>>>>>>>+<<<<<<<<]<<<<<<<<<<<[->>>>>>>>>>>+<<<<<<<<<<<]>>>>>>>>>>>>
+[->>>>>>>>>>>+<<<+<<<<<<<<]>>>>>>>>>>>[-<<<<<<<<<<<+>>>>>>>>>>>]<<<<<<<[->>>>>>>
++<<<+<<<<]>>>>>>>[-<<<<<<<+>>>>>>>]<]<[-]<<<<<<<<<<[-]>[-]>>>>>>>[-<<<<<<<<+>>>>
+>>>>]>[-<<<<<<<<+>>>>>>>>]<<<<<<<<<<<<<]
@@ -44,9 +44,26 @@ fn read_program_and_inputs() -> (Vec<u32>, VecDeque<u32>) {
     risc0_zkvm::guest::env::read()
 }
 
+// The output is also made public so that the host can check it. powdr's host captures it from
+// the printed output instead.
+#[cfg(feature = "powdr")]
+fn commit_output(_output: &str) {}
+
+#[cfg(feature = "sp1")]
+fn commit_output(output: &str) {
+    sp1_zkvm::io::commit_slice(output.as_bytes());
+}
+
+#[cfg(feature = "risc0")]
+fn commit_output(output: &str) {
+    risc0_zkvm::guest::env::commit_slice(output.as_bytes());
+}
+
 fn main() {
     let (program, inputs) = read_program_and_inputs();
+    // the host validated the program with the shared AST before encoding it
     let (_, output) = interpreter::run(program, inputs);
     let output = String::from_utf8(output).unwrap();
     println!("{output}");
+    commit_output(&output);
 }
@@ -0,0 +1,40 @@
+//! Cycle regions for the guest programs. Wrapping a section in [`region`] makes the evaluator
+//! report the cycles spent in it, summed over every time the region runs.
+//!
+//! - SP1: the `cycle-tracker-report` markers, which the executor sums per name.
+//! - RISC0: the difference of `env::cycle_count()` over the section, which is written to stderr in
+//!   a `cycle-region: <name> <cycles>` line for the evaluator to sum.
+//! - powdr: not tracked. The guest can't read the row count, and the executor doesn't pass it to
+//!   the query callbacks it calls for the guest's output, so a marker written by the guest can't be
+//!   placed in the trace by the evaluator either. The evaluator records the regions of powdr runs
+//!   as unsupported.
+//!
+//! Regions can be nested, in which case the cycles of the inner region also count towards the
+//! outer one.
+
+/// Runs `f` in the region `name`.
+#[cfg(feature = "sp1")]
+pub fn region<T>(name: &str, f: impl FnOnce() -> T) -> T {
+    println!("cycle-tracker-report-start: {name}");
+    let result = f();
+    println!("cycle-tracker-report-end: {name}");
+    result
+}
+
+/// Runs `f` in the region `name`.
+#[cfg(feature = "risc0")]
+pub fn region<T>(name: &str, f: impl FnOnce() -> T) -> T {
+    use risc0_zkvm::guest::env::{self, Write};
+
+    let start = env::cycle_count();
+    let result = f();
+    let cycles = env::cycle_count() - start;
+    env::stderr().write_slice(format!("cycle-region: {name} {cycles}\n").as_bytes());
+    result
+}
+
+/// Runs `f` in the region `name`.
+#[cfg(not(any(feature = "sp1", feature = "risc0")))]
+pub fn region<T>(_name: &str, f: impl FnOnce() -> T) -> T {
+    f()
+}
@@ -7,6 +7,7 @@
 #[cfg(feature = "powdr")]
 extern crate powdr_riscv_runtime;
 
+use cycle_region::region;
 use reth_primitives::B256;
 use revm::InMemoryDB;
 use sp1_reth_primitives::{
@@ -20,13 +21,13 @@ fn main() {
     let mut input = powdr_riscv_runtime::io::read::<SP1RethInput>(0);
 
     // Initialize the database.
-    let db = InMemoryDB::initialize(&mut input).unwrap();
+    let db = region("setup", || InMemoryDB::initialize(&mut input).unwrap());
 
     // Execute the block.
     let mut executor = EvmProcessor::<InMemoryDB> { input, db: Some(db), header: None };
-    executor.initialize();
-    executor.execute();
-    executor.finalize();
+    region("setup", || executor.initialize());
+    region("execute", || executor.execute());
+    region("finalize", || executor.finalize());
 
     // Print the resulting block hash.
     let hash = B256::from(keccak(alloy_rlp::encode(executor.header.unwrap())));
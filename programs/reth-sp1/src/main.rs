@@ -7,6 +7,7 @@
 #![no_main]
 sp1_zkvm::entrypoint!(main);
 
+use cycle_region::region;
 use reth_primitives::B256;
 use revm::InMemoryDB;
 use sp1_reth_primitives::db::InMemoryDBHelper;
@@ -21,7 +22,7 @@ fn main() {
     let mut input = sp1_zkvm::io::read::<SP1RethInput>();
 
     // Initialize the database.
-    let db = InMemoryDB::initialize(&mut input).unwrap();
+    let db = region("setup", || InMemoryDB::initialize(&mut input).unwrap());
 
     // Execute the block.
     let mut executor = EvmProcessor::<InMemoryDB> {
@@ -29,9 +30,9 @@ fn main() {
         db: Some(db),
         header: None,
     };
-    executor.initialize();
-    executor.execute();
-    executor.finalize();
+    region("setup", || executor.initialize());
+    region("execute", || executor.execute());
+    region("finalize", || executor.finalize());
 
     // Print the resulting block hash.
     let hash = B256::from(keccak(alloy_rlp::encode(executor.header.unwrap())));
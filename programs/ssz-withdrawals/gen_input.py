@@ -0,0 +1,217 @@
+"""Generate a withdrawals input file for the ssz-withdrawals program from mainnet data.
+
+The beacon states and blocks are fetched from a beacon node with the debug API enabled, and all
+proofs are built from them, so the input proves real withdrawals against a real block root. The
+states are large (a few hundred MB each), and the node must still serve the state at the end of
+the historical period of every slot in the range, so an archive node is needed for old slots.
+
+    pip install eth2spec
+    python3 gen_input.py --beacon-url http://localhost:5052 --out inputs/withdrawals_3.json
+
+The defaults reproduce the range of the original test vector: the block root d00c4da1... at slot
+8225000, slots [7855804, 7855807) and the eigenpod at e9cd1419....
+"""
+
+import argparse
+import json
+import os
+import urllib.error
+import urllib.request
+
+from eth2spec.capella import mainnet as spec
+
+CAPELLA_FORK_SLOT = 6209536
+SLOTS_PER_HISTORICAL_ROOT = 8192
+
+# Generalized indices of the proofs checked by the guest, see `src/beacon/prove.rs`.
+HEADER_STATE_ROOT_GINDEX = 11
+VALIDATORS_ROOT_GINDEX = 363
+VALIDATOR_GINDEX = 2**41
+HISTORICAL_SUMMARY_GINDEX = 12717129728
+SUMMARY_BLOCK_ROOTS_GINDEX = 2
+BLOCK_ROOT_GINDEX = 16384
+WITHDRAWALS_ROOT_GINDEX = 3230
+WITHDRAWAL_GINDEX = 32
+
+
+def get(url, path, ssz=False):
+    headers = {'Accept': 'application/octet-stream' if ssz else 'application/json'}
+    request = urllib.request.Request(url.rstrip('/') + path, headers=headers)
+    try:
+        with urllib.request.urlopen(request) as response:
+            data = response.read()
+    except urllib.error.HTTPError as e:
+        if e.code == 404:
+            return None
+        raise
+    return data if ssz else json.loads(data)['data']
+
+
+def get_state(url, state_id):
+    data = get(url, f'/eth/v2/debug/beacon/states/{state_id}', ssz=True)
+    assert data is not None, f'state {state_id} is not available'
+    return spec.BeaconState.decode_bytes(data)
+
+
+def get_block(url, slot):
+    """Returns the block at `slot`, or None if the slot was missed."""
+    data = get(url, f'/eth/v2/beacon/blocks/{slot}', ssz=True)
+    return None if data is None else spec.SignedBeaconBlock.decode_bytes(data).message
+
+
+def prove(backing, gindex):
+    """Returns the node at `gindex` below `backing` and its branch, from the leaf up."""
+    branch = []
+    node = gindex
+    while node > 1:
+        branch.append(backing.getter(node ^ 1).merkle_root())
+        node //= 2
+    return backing.getter(gindex).merkle_root(), branch
+
+
+def node_json(node):
+    return '0x' + bytes(node).hex()
+
+
+def proof_json(leaf, branch):
+    return {'leaf': node_json(leaf), 'branch': [node_json(n) for n in branch]}
+
+
+def generate(url, block_root, start_slot, end_slot, eigenpod_address):
+    header = get(url, f'/eth/v1/beacon/headers/{node_json(block_root)}')['header']['message']
+    header = spec.BeaconBlockHeader(
+        slot=int(header['slot']),
+        proposer_index=int(header['proposer_index']),
+        parent_root=bytes.fromhex(header['parent_root'][2:]),
+        state_root=bytes.fromhex(header['state_root'][2:]),
+        body_root=bytes.fromhex(header['body_root'][2:]),
+    )
+    assert bytes(header.hash_tree_root()) == block_root, 'header does not match the block root'
+    source_slot = int(header.slot)
+    assert source_slot - end_slot >= SLOTS_PER_HISTORICAL_ROOT, \
+        'slots must be at least 8192 slots before the source slot'
+
+    state = get_state(url, node_json(header.state_root))
+    assert state.hash_tree_root() == header.state_root, 'state does not match the header'
+    # The proofs against the block root go through the header, with the state grafted in.
+    block_tree = header.get_backing().setter(HEADER_STATE_ROOT_GINDEX)(state.get_backing())
+
+    # The block roots of each historical period, from the state at the end of the period.
+    periods = {}
+
+    def block_roots_tree(array_index):
+        if array_index not in periods:
+            summary = state.historical_summaries[array_index]
+            end = CAPELLA_FORK_SLOT + (array_index + 1) * SLOTS_PER_HISTORICAL_ROOT
+            block_roots = get_state(url, end).block_roots
+            assert block_roots.hash_tree_root() == summary.block_summary_root, \
+                f'block roots of period {array_index} do not match the historical summary'
+            summary_tree = summary.get_backing().setter(SUMMARY_BLOCK_ROOTS_GINDEX)(
+                block_roots.get_backing())
+            periods[array_index] = summary_tree
+        return periods[array_index]
+
+    slots = []
+    validator_indices = set()
+    for slot in range(start_slot, end_slot):
+        block = get_block(url, slot)
+        if block is None:
+            continue
+        payload = block.body.execution_payload
+        withdrawals = [
+            (i, w) for i, w in enumerate(payload.withdrawals) if bytes(w.address) == eigenpod_address
+        ]
+        if not withdrawals:
+            continue
+
+        array_index = (slot - CAPELLA_FORK_SLOT) // SLOTS_PER_HISTORICAL_ROOT
+        summary_tree = block_roots_tree(array_index)
+        root, _ = prove(summary_tree, BLOCK_ROOT_GINDEX + slot % SLOTS_PER_HISTORICAL_ROOT)
+        assert root == block.hash_tree_root(), f'block root of slot {slot} does not match'
+
+        withdrawals_tree = payload.withdrawals.get_backing()
+        validator_indices.update(int(w.validator_index) for _, w in withdrawals)
+        slots.append({
+            'slot': str(slot),
+            'historical_summary': proof_json(
+                *prove(block_tree, HISTORICAL_SUMMARY_GINDEX + array_index)),
+            'historical_block_root': proof_json(
+                *prove(summary_tree, BLOCK_ROOT_GINDEX + slot % SLOTS_PER_HISTORICAL_ROOT)),
+            'withdrawals_root': proof_json(
+                *prove(block.get_backing(), WITHDRAWALS_ROOT_GINDEX)),
+            'withdrawals': [
+                {
+                    'index': i,
+                    'withdrawal': {
+                        'index': int(w.index),
+                        'validatorIndex': int(w.validator_index),
+                        'address': bytes(w.address).hex(),
+                        'amount': str(int(w.amount)),
+                    },
+                    'branch': [
+                        node_json(n) for n in prove(withdrawals_tree, WITHDRAWAL_GINDEX + i)[1]
+                    ],
+                }
+                for i, w in withdrawals
+            ],
+        })
+
+    validators_tree = state.validators.get_backing()
+    validators = []
+    for index in sorted(validator_indices):
+        v = state.validators[index]
+        validators.append({
+            'index': str(index),
+            'validator': {
+                'pubkey': bytes(v.pubkey).hex(),
+                'withdrawal_credentials': node_json(v.withdrawal_credentials),
+                'effective_balance': str(int(v.effective_balance)),
+                'slashed': bool(v.slashed),
+                'activation_eligibility_epoch': str(int(v.activation_eligibility_epoch)),
+                'activation_epoch': str(int(v.activation_epoch)),
+                'exit_epoch': str(int(v.exit_epoch)),
+                'withdrawable_epoch': str(int(v.withdrawable_epoch)),
+            },
+            'branch': [node_json(n) for n in prove(validators_tree, VALIDATOR_GINDEX + index)[1]],
+        })
+
+    return {
+        'block_root': node_json(block_root),
+        'start_slot': str(start_slot),
+        'end_slot': str(end_slot),
+        'eigenpod_address': eigenpod_address.hex(),
+        'header': {
+            'slot': str(source_slot),
+            'proposer_index': str(int(header.proposer_index)),
+            'parent_root': node_json(header.parent_root),
+            'state_root': node_json(header.state_root),
+            'body_root': node_json(header.body_root),
+        },
+        'validators_root': proof_json(*prove(block_tree, VALIDATORS_ROOT_GINDEX)),
+        'validators': validators,
+        'slots': slots,
+    }
+
+
+if __name__ == '__main__':
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument('--beacon-url', required=True)
+    parser.add_argument(
+        '--block-root',
+        default='0xd00c4da1a3ad4d42bd35f128544227d19e163194569d69d54a3d14112e3c897c')
+    parser.add_argument('--start-slot', type=int, default=7855804)
+    parser.add_argument('--end-slot', type=int, default=7855807)
+    parser.add_argument('--eigenpod-address', default='e9cd1419a015dd05d47f6139f5b8e86b1e9e5cdd')
+    parser.add_argument('--out', required=True)
+    args = parser.parse_args()
+
+    data = generate(
+        args.beacon_url,
+        bytes.fromhex(args.block_root.removeprefix('0x')),
+        args.start_slot,
+        args.end_slot,
+        bytes.fromhex(args.eigenpod_address.removeprefix('0x')),
+    )
+    os.makedirs(os.path.dirname(args.out) or '.', exist_ok=True)
+    with open(args.out, 'w') as f:
+        json.dump(data, f, indent=2)
@@ -0,0 +1,315 @@
+//! Parsing of the JSON input files written by `gen_input.py`.
+//!
+//! The files follow the beacon API conventions: integers are mostly decimal strings and byte
+//! strings are hex, with or without a `0x` prefix.
+
+use crate::*;
+use serde::{Deserialize, Deserializer};
+
+/// Parses a JSON withdrawals input file.
+pub fn from_slice(json: &[u8]) -> serde_json::Result<WithdrawalsInput> {
+    let Input(input) = serde_json::from_slice(json)?;
+    Ok(input)
+}
+
+#[derive(Deserialize)]
+struct Input(#[serde(with = "WithdrawalsInputDef")] WithdrawalsInput);
+
+#[derive(Deserialize)]
+#[serde(remote = "WithdrawalsInput")]
+struct WithdrawalsInputDef {
+    #[serde(deserialize_with = "hex_array")]
+    block_root: Root,
+    #[serde(deserialize_with = "int")]
+    start_slot: u64,
+    #[serde(deserialize_with = "int")]
+    end_slot: u64,
+    #[serde(deserialize_with = "hex_array")]
+    eigenpod_address: Address,
+    #[serde(with = "BeaconBlockHeaderDef")]
+    header: BeaconBlockHeader,
+    #[serde(with = "MerkleProofDef")]
+    validators_root: MerkleProof,
+    #[serde(deserialize_with = "validator_proofs")]
+    validators: Vec<ValidatorProof>,
+    #[serde(deserialize_with = "slot_proofs")]
+    slots: Vec<SlotProofs>,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "MerkleProof")]
+struct MerkleProofDef {
+    #[serde(deserialize_with = "hex_array")]
+    leaf: Root,
+    #[serde(deserialize_with = "branch")]
+    branch: Vec<Root>,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "ValidatorProof")]
+struct ValidatorProofDef {
+    #[serde(deserialize_with = "int")]
+    index: u64,
+    #[serde(with = "ValidatorDef")]
+    validator: Validator,
+    #[serde(deserialize_with = "branch")]
+    branch: Vec<Root>,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "SlotProofs")]
+struct SlotProofsDef {
+    #[serde(deserialize_with = "int")]
+    slot: u64,
+    #[serde(with = "MerkleProofDef")]
+    historical_summary: MerkleProof,
+    #[serde(with = "MerkleProofDef")]
+    historical_block_root: MerkleProof,
+    #[serde(with = "MerkleProofDef")]
+    withdrawals_root: MerkleProof,
+    #[serde(deserialize_with = "withdrawal_proofs")]
+    withdrawals: Vec<WithdrawalProof>,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "WithdrawalProof")]
+struct WithdrawalProofDef {
+    index: u32,
+    #[serde(with = "WithdrawalDef")]
+    withdrawal: Withdrawal,
+    #[serde(deserialize_with = "branch")]
+    branch: Vec<Root>,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "BeaconBlockHeader")]
+struct BeaconBlockHeaderDef {
+    #[serde(deserialize_with = "int")]
+    slot: u64,
+    #[serde(deserialize_with = "int")]
+    proposer_index: u64,
+    #[serde(deserialize_with = "hex_array")]
+    parent_root: Root,
+    #[serde(deserialize_with = "hex_array")]
+    state_root: Root,
+    #[serde(deserialize_with = "hex_array")]
+    body_root: Root,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "Validator")]
+struct ValidatorDef {
+    #[serde(deserialize_with = "hex_vec")]
+    pubkey: Vec<u8>,
+    #[serde(deserialize_with = "hex_array")]
+    withdrawal_credentials: Root,
+    #[serde(deserialize_with = "int")]
+    effective_balance: u64,
+    slashed: bool,
+    #[serde(deserialize_with = "int")]
+    activation_eligibility_epoch: u64,
+    #[serde(deserialize_with = "int")]
+    activation_epoch: u64,
+    #[serde(deserialize_with = "int")]
+    exit_epoch: u64,
+    #[serde(deserialize_with = "int")]
+    withdrawable_epoch: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(remote = "Withdrawal", rename_all = "camelCase")]
+struct WithdrawalDef {
+    #[serde(deserialize_with = "int")]
+    index: u64,
+    #[serde(deserialize_with = "int")]
+    validator_index: u64,
+    #[serde(deserialize_with = "hex_array")]
+    address: Address,
+    #[serde(deserialize_with = "int")]
+    amount: u64,
+}
+
+/// Deserializes a list of remote types through their `...Def` mirror.
+macro_rules! remote_vec {
+    ($name:ident, $ty:ty, $def:literal) => {
+        fn $name<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<$ty>, D::Error> {
+            #[derive(Deserialize)]
+            struct Item(#[serde(with = $def)] $ty);
+            let items = Vec::<Item>::deserialize(deserializer)?;
+            Ok(items.into_iter().map(|Item(item)| item).collect())
+        }
+    };
+}
+
+remote_vec!(validator_proofs, ValidatorProof, "ValidatorProofDef");
+remote_vec!(slot_proofs, SlotProofs, "SlotProofsDef");
+remote_vec!(withdrawal_proofs, WithdrawalProof, "WithdrawalProofDef");
+
+/// An integer written either as a JSON number or as a decimal string.
+fn int<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Int {
+        Number(u64),
+        String(String),
+    }
+    match Int::deserialize(deserializer)? {
+        Int::Number(n) => Ok(n),
+        Int::String(s) => s.parse().map_err(serde::de::Error::custom),
+    }
+}
+
+fn decode_hex<E: serde::de::Error>(s: &str) -> Result<Vec<u8>, E> {
+    hex::decode(s.strip_prefix("0x").unwrap_or(s)).map_err(E::custom)
+}
+
+fn hex_vec<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    decode_hex(&String::deserialize(deserializer)?)
+}
+
+fn hex_array<'de, D: Deserializer<'de>, const N: usize>(
+    deserializer: D,
+) -> Result<[u8; N], D::Error> {
+    let bytes = hex_vec(deserializer)?;
+    let len = bytes.len();
+    bytes
+        .try_into()
+        .map_err(|_| serde::de::Error::invalid_length(len, &format!("{N} bytes").as_str()))
+}
+
+fn branch<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Root>, D::Error> {
+    Vec::<String>::deserialize(deserializer)?
+        .iter()
+        .map(|node| {
+            let bytes = decode_hex::<D::Error>(node)?;
+            bytes.try_into().map_err(|_| serde::de::Error::custom("a node must be 32 bytes"))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn node(byte: u8) -> String {
+        format!("0x{}", hex::encode([byte; 32]))
+    }
+
+    fn proof(leaf: u8, branch: &[u8]) -> Value {
+        json!({ "leaf": node(leaf), "branch": branch.iter().map(|&n| node(n)).collect::<Vec<_>>() })
+    }
+
+    /// An input with one validator and one slot with one withdrawal, in the format of
+    /// `gen_input.py`.
+    fn fixture() -> Value {
+        json!({
+            "block_root": node(1),
+            "start_slot": "7855804",
+            "end_slot": "7855807",
+            "eigenpod_address": "e9cd1419a015dd05d47f6139f5b8e86b1e9e5cdd",
+            "header": {
+                "slot": "8225000",
+                "proposer_index": "42",
+                "parent_root": node(2),
+                "state_root": node(3),
+                "body_root": node(4),
+            },
+            "validators_root": proof(5, &[6, 7]),
+            "validators": [{
+                "index": "1000",
+                "validator": {
+                    "pubkey": hex::encode([8; 48]),
+                    "withdrawal_credentials": node(9),
+                    "effective_balance": "32000000000",
+                    "slashed": false,
+                    "activation_eligibility_epoch": "0",
+                    "activation_epoch": "0",
+                    "exit_epoch": "18446744073709551615",
+                    "withdrawable_epoch": "18446744073709551615",
+                },
+                "branch": [node(10)],
+            }],
+            "slots": [{
+                "slot": "7855805",
+                "historical_summary": proof(11, &[12]),
+                "historical_block_root": proof(13, &[14]),
+                "withdrawals_root": proof(15, &[16]),
+                "withdrawals": [{
+                    "index": 3,
+                    "withdrawal": {
+                        // a JSON number is accepted as well as a decimal string
+                        "index": 17,
+                        "validatorIndex": "1000",
+                        "address": "0xe9cd1419a015dd05d47f6139f5b8e86b1e9e5cdd",
+                        "amount": "12345",
+                    },
+                    "branch": [node(18), node(19)],
+                }],
+            }],
+        })
+    }
+
+    fn parse(input: &Value) -> serde_json::Result<WithdrawalsInput> {
+        from_slice(&serde_json::to_vec(input).unwrap())
+    }
+
+    #[test]
+    fn parses_an_input() {
+        let input = parse(&fixture()).unwrap();
+        assert_eq!(input.block_root, [1; 32]);
+        assert_eq!((input.start_slot, input.end_slot), (7855804, 7855807));
+        assert_eq!(hex::encode(input.eigenpod_address), "e9cd1419a015dd05d47f6139f5b8e86b1e9e5cdd");
+        assert_eq!((input.header.slot, input.header.body_root), (8225000, [4; 32]));
+        assert_eq!(input.validators_root.branch, vec![[6; 32], [7; 32]]);
+
+        let validator = &input.validators[0];
+        assert_eq!((validator.index, validator.validator.pubkey.len()), (1000, 48));
+        assert_eq!(validator.validator.withdrawable_epoch, u64::MAX);
+
+        let slot = &input.slots[0];
+        assert_eq!((slot.slot, slot.withdrawals_root.leaf), (7855805, [15; 32]));
+        let withdrawal = &slot.withdrawals[0];
+        assert_eq!(withdrawal.index, 3);
+        assert_eq!(
+            (withdrawal.withdrawal.index, withdrawal.withdrawal.validator_index),
+            (17, 1000)
+        );
+        assert_eq!(withdrawal.withdrawal.address, input.eigenpod_address);
+        assert_eq!(withdrawal.withdrawal.amount, 12345);
+        assert_eq!(withdrawal.branch.len(), 2);
+    }
+
+    #[test]
+    fn malformed_hex_is_rejected() {
+        let mut input = fixture();
+        input["block_root"] = json!(format!("0x{}", "zz".repeat(32)));
+        let err = parse(&input).unwrap_err().to_string();
+        assert!(err.contains("Invalid character"), "{err}");
+
+        let mut input = fixture();
+        input["slots"][0]["withdrawals"][0]["branch"][1] = json!("0x123");
+        let err = parse(&input).unwrap_err().to_string();
+        assert!(err.contains("Odd number of digits"), "{err}");
+    }
+
+    #[test]
+    fn proof_nodes_of_the_wrong_length_are_rejected() {
+        let mut input = fixture();
+        input["validators_root"]["branch"][1] = json!(hex::encode([7; 31]));
+        let err = parse(&input).unwrap_err().to_string();
+        assert!(err.contains("a node must be 32 bytes"), "{err}");
+
+        let mut input = fixture();
+        input["slots"][0]["historical_block_root"]["leaf"] = json!(hex::encode([13; 33]));
+        let err = parse(&input).unwrap_err().to_string();
+        assert!(err.contains("invalid length 33, expected 32 bytes"), "{err}");
+    }
+
+    #[test]
+    fn invalid_integers_are_rejected() {
+        let mut input = fixture();
+        input["end_slot"] = json!("0x10");
+        assert!(parse(&input).is_err());
+    }
+}
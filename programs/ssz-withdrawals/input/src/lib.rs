@@ -0,0 +1,99 @@
+//! The witness data of the ssz-withdrawals program, shared by the guest and the evaluator.
+//!
+//! The host parses the JSON input files (see the `json` feature) and sends these plain types to
+//! the guest, so the guest only deserializes fixed-size binary data.
+
+#[cfg(feature = "json")]
+pub mod json;
+
+/// An SSZ merkle node.
+pub type Root = [u8; 32];
+
+/// An execution layer address.
+pub type Address = [u8; 20];
+
+/// The witness data for a withdrawals proof.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct WithdrawalsInput {
+    /// The beacon block root that all proofs are rooted at.
+    pub block_root: Root,
+    pub start_slot: u64,
+    pub end_slot: u64,
+    pub eigenpod_address: Address,
+    /// The beacon block header that hashes to `block_root`.
+    pub header: BeaconBlockHeader,
+    /// The beacon state's validators root and its proof against `block_root`.
+    pub validators_root: MerkleProof,
+    /// The validators referenced by the withdrawals in `slots`.
+    pub validators: Vec<ValidatorProof>,
+    /// The withdrawals to `eigenpod_address` in [start_slot, end_slot), grouped by slot.
+    pub slots: Vec<SlotProofs>,
+}
+
+/// A leaf and the SSZ merkle branch proving it against some root.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct MerkleProof {
+    pub leaf: Root,
+    pub branch: Vec<Root>,
+}
+
+/// A validator and its SSZ proof against the validators root.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct ValidatorProof {
+    pub index: u64,
+    pub validator: Validator,
+    pub branch: Vec<Root>,
+}
+
+/// The proofs needed to read the withdrawals of a single historical slot.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct SlotProofs {
+    pub slot: u64,
+    /// The historical summary root containing `slot`, proven against `block_root`.
+    pub historical_summary: MerkleProof,
+    /// The block root of `slot`, proven against the historical summary root.
+    pub historical_block_root: MerkleProof,
+    /// The withdrawals root of the historical block, proven against its block root.
+    pub withdrawals_root: MerkleProof,
+    /// The withdrawals in the historical block, proven against the withdrawals root.
+    pub withdrawals: Vec<WithdrawalProof>,
+}
+
+/// A withdrawal and its SSZ proof against the withdrawals root.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct WithdrawalProof {
+    /// The position of the withdrawal in the block's withdrawals list, in [0, 16).
+    pub index: u32,
+    pub withdrawal: Withdrawal,
+    pub branch: Vec<Root>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct BeaconBlockHeader {
+    pub slot: u64,
+    pub proposer_index: u64,
+    pub parent_root: Root,
+    pub state_root: Root,
+    pub body_root: Root,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct Validator {
+    /// The 48-byte BLS public key.
+    pub pubkey: Vec<u8>,
+    pub withdrawal_credentials: Root,
+    pub effective_balance: u64,
+    pub slashed: bool,
+    pub activation_eligibility_epoch: u64,
+    pub activation_epoch: u64,
+    pub exit_epoch: u64,
+    pub withdrawable_epoch: u64,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct Withdrawal {
+    pub index: u64,
+    pub validator_index: u64,
+    pub address: Address,
+    pub amount: u64,
+}
@@ -1,148 +1,136 @@
-use crate::beacon::hints;
-use crate::beacon::is_valid_merkle_big_branch;
-use crate::beacon::types::*;
+use crate::beacon::{hints::*, is_valid_merkle_big_branch, node_from_bytes, types::*};
 use ssz_rs::prelude::*;
-use std::hint::black_box;
-use std::str::FromStr;
+use std::{hint::black_box, str::FromStr};
 
-// TODO: Assert or return validity in these functions when using real data
-
-pub fn block_header(block_root: Node) -> BeaconBlockHeader {
-    let mut header = black_box(hints::beacon_header_proof(block_root));
+pub fn block_header(
+    block_root: Node,
+    header: &ssz_withdrawals_input::BeaconBlockHeader,
+) -> BeaconBlockHeader {
+    let mut header = black_box(BeaconBlockHeader::from(header));
 
     let header_root = black_box(header.hash_tree_root().unwrap());
-    println!("header root: {:?}", header_root);
-
-    // TODO: with real data, assert that header_root == block_root
-    // assert_eq!(header_root, block_root);
+    assert_eq!(header_root, block_root, "invalid beacon block header");
 
     header
 }
 
-pub fn withdrawals_root(block_root: Node) -> Node {
-    let (leaf, branch) = black_box(hints::withdrawals_root_proof(block_root));
+pub fn withdrawals_root(block_root: Node, proof: &MerkleProof) -> Node {
     let depth = 11;
     let index = alloy_primitives::U256::from(3230);
 
+    let leaf = node_from_bytes(proof.leaf);
     let valid = black_box(is_valid_merkle_big_branch(
         &leaf,
-        branch.iter(),
+        proof.branch.iter(),
         depth,
         index,
         &block_root,
     ));
-    println!("withdrawals root valid: {}", valid);
+    assert!(valid, "invalid withdrawals root proof");
     leaf
 }
 
-pub fn withdrawal(block_root: Node, withdrawals_root: Node, index: u32) -> Withdrawal {
-    let (mut withdrawal, branch) = black_box(hints::withdrawal_proof(block_root, index));
+pub fn withdrawal(withdrawals_root: Node, proof: &WithdrawalProof) -> Withdrawal {
+    let mut withdrawal = black_box(Withdrawal::from(&proof.withdrawal));
     let leaf = withdrawal.hash_tree_root().unwrap();
     let depth = 5;
-    let index = alloy_primitives::U256::from(32);
+    let index = alloy_primitives::U256::from(32 + proof.index);
 
     let valid = black_box(is_valid_merkle_big_branch(
         &leaf,
-        branch.iter(),
+        proof.branch.iter(),
         depth,
         index,
         &withdrawals_root,
     ));
-    println!("withdrawal valid: {}", valid);
+    assert!(valid, "invalid withdrawal proof for index {}", proof.index);
     withdrawal
 }
 
-pub fn validators_root(block_root: Node) -> Node {
-    let (leaf, branch) = black_box(hints::validators_root_proof(block_root));
+pub fn validators_root(block_root: Node, proof: &MerkleProof) -> Node {
     let depth = 8;
     let index = alloy_primitives::U256::from(363);
-    // let root = node_from_bytes(hex!(
-    //     "88d257af10bc873ab8e41bfb9fd51be55249f78549ea0dbaa0c2deda979368e7"
-    // ));
 
+    let leaf = node_from_bytes(proof.leaf);
     let valid = black_box(is_valid_merkle_big_branch(
         &leaf,
-        branch.iter(),
+        proof.branch.iter(),
         depth,
         index,
         &block_root,
     ));
-    println!("validators root valid: {}", valid);
+    assert!(valid, "invalid validators root proof");
     leaf
 }
 
-pub fn validator(block_root: Node, validators_root: Node, validator_index: u64) -> Validator {
-    let (mut validator, branch) = black_box(hints::validator_proof(block_root, validator_index));
+pub fn validator(validators_root: Node, proof: &ValidatorProof) -> Validator {
+    let mut validator = black_box(Validator::from(&proof.validator));
     let leaf = validator.hash_tree_root().unwrap();
     let depth = 41;
     // ssz.phase0.Validators.getPathInfo([0]).gindex
     let index = alloy_primitives::U256::from_str("2199023255552")
         .unwrap()
-        .wrapping_add(alloy_primitives::U256::from(validator_index));
-    // let root = node_from_bytes(hex!(
-    //     "8ada0d639d94919c8a8aa62f13bbf5f0a0bf3e4340aa01679e533a4f68a54dc0"
-    // ));
+        .wrapping_add(alloy_primitives::U256::from(proof.index));
     let valid = black_box(is_valid_merkle_big_branch(
         &leaf,
-        branch.iter(),
+        proof.branch.iter(),
         depth,
         index,
         &validators_root,
     ));
-    println!("validator valid: {}", valid);
+    assert!(valid, "invalid validator proof for index {}", proof.index);
     validator
 }
 
-pub fn historical_far_slot(block_root: Node, target_slot: u64) -> Node {
-    let (leaf, branch) = black_box(hints::historical_far_slot_proof(block_root, target_slot));
+pub fn historical_far_slot(block_root: Node, target_slot: u64, proof: &MerkleProof) -> Node {
     let depth = 33;
     let array_index = (target_slot - 6209536) / 8192;
     let index = alloy_primitives::U256::from_str("12717129728")
         .unwrap()
         .wrapping_add(alloy_primitives::U256::from(array_index));
-    // let root = node_from_bytes(hex!(
-    //     "d00c4da1a3ad4d42bd35f128544227d19e163194569d69d54a3d14112e3c897c"
-    // ));
 
+    let leaf = node_from_bytes(proof.leaf);
     let valid = black_box(is_valid_merkle_big_branch(
         &leaf,
-        branch.iter(),
+        proof.branch.iter(),
         depth,
         index,
         &block_root,
     ));
-    println!("historical far slot valid: {}", valid);
+    assert!(valid, "invalid historical summary proof for slot {}", target_slot);
     leaf
 }
 
-fn historical_far_slot_blockroot(block_root: Node, summary_root: Node, target_slot: u64) -> Node {
-    let (leaf, branch) = black_box(hints::historical_far_slot_blockroot_proof(
-        block_root,
-        target_slot,
-    ));
+fn historical_far_slot_blockroot(
+    summary_root: Node,
+    target_slot: u64,
+    proof: &MerkleProof,
+) -> Node {
     let depth = 14;
     let array_index = (target_slot) % 8192;
     let index = alloy_primitives::U256::from(16384 + array_index);
-    // let root = node_from_bytes(hex!(
-    //     "1d52ab18adbab483661ee3dd7ebc62691abe30c1ac619a120a4d3050ec0f7c4b"
-    // ));
 
+    let leaf = node_from_bytes(proof.leaf);
     let valid = black_box(is_valid_merkle_big_branch(
         &leaf,
-        branch.iter(),
+        proof.branch.iter(),
         depth,
         index,
         &summary_root,
     ));
-    println!("historical far slot blockroot valid: {}", valid);
+    assert!(valid, "invalid historical block root proof for slot {}", target_slot);
     leaf
 }
 
-pub fn historical_block_root(block_root: Node, source_slot: u64, target_slot: u64) -> Node {
+/// Proves the block root of `slot_proofs.slot` against `block_root`. The target slot must be at
+/// most (source_slot - 8192).
+pub fn historical_block_root(block_root: Node, source_slot: u64, slot_proofs: &SlotProofs) -> Node {
+    let target_slot = slot_proofs.slot;
     if source_slot - target_slot < 8192 {
         unimplemented!()
     } else {
-        let summary_root = historical_far_slot(block_root, target_slot);
-        historical_far_slot_blockroot(block_root, summary_root, target_slot)
+        let summary_root =
+            historical_far_slot(block_root, target_slot, &slot_proofs.historical_summary);
+        historical_far_slot_blockroot(summary_root, target_slot, &slot_proofs.historical_block_root)
     }
 }
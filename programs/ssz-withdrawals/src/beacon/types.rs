@@ -1,7 +1,6 @@
-use crate::DeserializeError;
-use serde;
-use serde_with::{serde_as, DisplayFromStr};
+use crate::{beacon::node_from_bytes, DeserializeError};
 use ssz_rs::prelude::{Deserialize, Node, SimpleSerialize, Sized, Vector};
+use ssz_withdrawals_input as input;
 
 pub type Bytes32 = Node;
 pub type BLSPubkey = Vector<u8, 48>;
@@ -19,30 +18,57 @@ pub struct Validator {
     pub withdrawable_epoch: u64,
 }
 
-#[serde_as]
-#[derive(
-    serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Default, Clone, SimpleSerialize,
-)]
+#[derive(PartialEq, Eq, Debug, Default, Clone, SimpleSerialize)]
 pub struct BeaconBlockHeader {
-    #[serde_as(as = "DisplayFromStr")]
     pub slot: u64,
-    #[serde_as(as = "DisplayFromStr")]
     pub proposer_index: u64,
     pub parent_root: Bytes32,
     pub state_root: Bytes32,
     pub body_root: Bytes32,
 }
 
-#[serde_as]
-#[derive(
-    serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Default, Clone, SimpleSerialize,
-)]
-#[serde(rename_all = "camelCase")]
+#[derive(PartialEq, Eq, Debug, Default, Clone, SimpleSerialize)]
 pub struct Withdrawal {
     pub index: u64,
     pub validator_index: u64,
-    #[serde_as(as = "serde_with::hex::Hex")]
     pub address: ExecutionAddress,
-    #[serde_as(as = "DisplayFromStr")]
     pub amount: u64,
 }
+
+impl From<&input::Validator> for Validator {
+    fn from(validator: &input::Validator) -> Self {
+        Validator {
+            pubkey: Vector::try_from(validator.pubkey.clone()).expect("invalid validator pubkey"),
+            withdrawal_credentials: node_from_bytes(validator.withdrawal_credentials),
+            effective_balance: validator.effective_balance,
+            slashed: validator.slashed,
+            activation_eligibility_epoch: validator.activation_eligibility_epoch,
+            activation_epoch: validator.activation_epoch,
+            exit_epoch: validator.exit_epoch,
+            withdrawable_epoch: validator.withdrawable_epoch,
+        }
+    }
+}
+
+impl From<&input::BeaconBlockHeader> for BeaconBlockHeader {
+    fn from(header: &input::BeaconBlockHeader) -> Self {
+        BeaconBlockHeader {
+            slot: header.slot,
+            proposer_index: header.proposer_index,
+            parent_root: node_from_bytes(header.parent_root),
+            state_root: node_from_bytes(header.state_root),
+            body_root: node_from_bytes(header.body_root),
+        }
+    }
+}
+
+impl From<&input::Withdrawal> for Withdrawal {
+    fn from(withdrawal: &input::Withdrawal) -> Self {
+        Withdrawal {
+            index: withdrawal.index,
+            validator_index: withdrawal.validator_index,
+            address: Vector::try_from(withdrawal.address.to_vec()).unwrap(),
+            amount: withdrawal.amount,
+        }
+    }
+}
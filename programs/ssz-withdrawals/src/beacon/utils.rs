@@ -13,9 +13,9 @@ cfg_if::cfg_if! {
 
 // From https://github.com/ralexstokes/ssz-rs/blob/main/ssz-rs/src/merkleization/proofs.rs
 // Modified to use alloy_primitives::U256 instead of u64 for large gindex values
-pub fn is_valid_merkle_big_branch<'a>(
+pub fn is_valid_merkle_big_branch<'a, B: AsRef<[u8]> + 'a>(
     leaf: &Node,
-    mut branch: impl Iterator<Item = &'a Node>,
+    mut branch: impl Iterator<Item = &'a B>,
     depth: usize,
     index: U256,
     root: &Node,
@@ -40,12 +40,6 @@ pub fn is_valid_merkle_big_branch<'a>(
     root == value
 }
 
-pub fn branch_from_bytes(s: &[[u8; 32]]) -> Vec<Node> {
-    s.iter()
-        .map(|hex| node_from_bytes(*hex))
-        .collect::<Vec<Node>>()
-}
-
 pub fn node_from_bytes(s: [u8; 32]) -> Node {
     Node::try_from(&s[..]).unwrap()
 }
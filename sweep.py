@@ -4,13 +4,15 @@ from itertools import product
 
 filename = 'benchmark'
 trials = 1
-options_program = ['loop', 'fibonacci', 'tendermint', 'keccak', 'brainfuck', 'brainfuck-asm', 'brainfuck-compiler']
+options_program = ['loop', 'fibonacci', 'tendermint', 'keccak', 'brainfuck', 'brainfuck-asm', 'brainfuck-compiler', 'brainfuck-compiler-opt']
 options_prover = ['sp1', 'risc0', 'powdr-plonky3']
 options_hashfn = ['poseidon']
 options_shard_size = [20]
 
-powdr_only_programs = ['brainfuck-compiler', 'brainfuck-asm']
-args_brainfuck = ['programs/brainfuck/rot13.bf', 'programs/brainfuck/rot13.in']
+powdr_only_programs = ['brainfuck-compiler', 'brainfuck-compiler-opt', 'brainfuck-asm']
+args_brainfuck = ['--bf-case', 'rot13']
+# generated with programs/ssz-withdrawals/gen_input.py, see the README
+args_ssz_withdrawals = ['programs/ssz-withdrawals/inputs/withdrawals_3.json']
 
 ## for reth, use these
 # options_program = ['reth1', 'reth2']
@@ -28,6 +30,9 @@ for program, prover, hashfn, shard_size in option_combinations:
     if not prover.startswith('powdr') and program in powdr_only_programs:
         print(f'Skipping: {program}, {prover}, {hashfn}, {shard_size} (program not supported by prover)')
         continue
+    if program == 'ssz-withdrawals' and not os.path.exists(args_ssz_withdrawals[0]):
+        print(f'Skipping: {program}, {prover}, {hashfn}, {shard_size} ({args_ssz_withdrawals[0]} not generated, see programs/ssz-withdrawals/gen_input.py)')
+        continue
     print(f'Running: {program}, {prover}, {hashfn}, {shard_size}')
     env = os.environ.copy();
     env['MAX_DEGREE_LOG'] = str(shard_size)
@@ -38,5 +43,7 @@ for program, prover, hashfn, shard_size in option_combinations:
             subprocess.run(['bash', 'eval.sh', 'reth', prover, hashfn, str(shard_size), filename, options_block_2], env=env)
         elif program.startswith('brainfuck'):
             subprocess.run(['bash', 'eval.sh', program, prover, hashfn, str(shard_size), filename, args_brainfuck[0], args_brainfuck[1]], env=env)
+        elif program == 'ssz-withdrawals':
+            subprocess.run(['bash', 'eval.sh', program, prover, hashfn, str(shard_size), filename, args_ssz_withdrawals[0]], env=env)
         else:
             subprocess.run(['bash', 'eval.sh', program, prover, hashfn, str(shard_size), filename], env=env)